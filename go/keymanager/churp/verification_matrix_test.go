@@ -0,0 +1,47 @@
+package churp
+
+import (
+	"encoding/hex"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+
+	"github.com/oasisprotocol/oasis-core/go/common/cbor"
+)
+
+// verificationMatrixHex is the legacy byte representation of the verification
+// matrix of the bivariate polynomial B(x,y) = 1 + 2y + 3x + 4xy over the
+// NIST P-384 curve, as produced by the secret-sharing crate.
+const verificationMatrixHex = "010103aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741" +
+	"e082542a385502f25dbf55296c3a545e3872760ab70208d999057ba3d2d96926" +
+	"0045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96" +
+	"a9c75295df6103077a41d4606ffa1464793c7e5fdc7d98cb9d3910202dcd06be" +
+	"a4f240d3566da6b408bbae5026580d02d7e5c70500c83103138251cd52ac9298" +
+	"c1c8aad977321deb97e709bd0b4ca0aca55dc8ad51dcfc9d1589a1597e3a5120" +
+	"e1efd631c63e1835"
+
+// verificationMatrixCBORHex is the CBOR encoding of the verification matrix.
+// The test below checks that the Go encoder produces exactly these bytes,
+// and the secret-sharing crate's cbor feature is checked against the same
+// fixture (secret-sharing/src/vss/cbor.rs).
+//
+// The encoding is logged by running, from the go directory:
+//
+//	go test -v -run TestVerificationMatrixCBOR ./keymanager/churp/
+const verificationMatrixCBORHex = "58c6" + verificationMatrixHex
+
+func TestVerificationMatrixCBOR(t *testing.T) {
+	require := require.New(t)
+
+	matrix, err := hex.DecodeString(verificationMatrixHex)
+	require.NoError(err, "hex.DecodeString")
+
+	enc := cbor.Marshal(matrix)
+	t.Logf("CBOR encoding: %s", hex.EncodeToString(enc))
+	require.Equal(verificationMatrixCBORHex, hex.EncodeToString(enc), "encoding should match the fixture")
+
+	var dec []byte
+	err = cbor.Unmarshal(enc, &dec)
+	require.NoError(err, "Unmarshal")
+	require.Equal(matrix, dec, "decoded matrix should match")
+}
@@ -32,15 +32,10 @@ use oasis_core_runtime::{
     Protocol,
 };
 
-use secret_sharing::{
-    churp::{
-        encode_shareholder, CommitteeChanged, CommitteeUnchanged, Dealer, DealingPhase, Handoff,
-        HandoffKind, Shareholder, SwitchPoint, VerifiableSecretShare,
-    },
-    kdc::KeySharer,
-    poly::{scalar_from_bytes, scalar_to_bytes},
-    suites::{p384, Suite},
-    vss::VerificationMatrix,
+use secret_sharing::api::{
+    encode_shareholder, p384, scalar_from_bytes, scalar_to_bytes, CommitteeChanged,
+    CommitteeUnchanged, Dealer, DealingPhase, Handoff, HandoffKind, KeySharer, Shareholder, Suite,
+    SwitchPoint, VerifiableSecretShare, VerificationMatrix,
 };
 use zeroize::Zeroize;
 
@@ -3,7 +3,7 @@ pub use std::{convert::TryInto, sync::Arc};
 
 use anyhow::Result;
 use group::{ff::PrimeField, Group, GroupEncoding};
-use secret_sharing::{churp::VerifiableSecretShare, poly::BivariatePolynomial};
+use secret_sharing::api::{BivariatePolynomial, VerifiableSecretShare};
 use sgx_isa::Keypolicy;
 
 use oasis_core_runtime::{
@@ -341,10 +341,7 @@ mod tests {
 
     use oasis_core_runtime::storage::{KeyValue, UntrustedInMemoryStorage};
 
-    use secret_sharing::{
-        churp::{SecretShare, VerifiableSecretShare},
-        poly, vss,
-    };
+    use secret_sharing::api::{self, SecretShare, VerifiableSecretShare};
 
     use crate::churp::storage::{
         BIVARIATE_POLYNOMIAL_SEAL_CONTEXT, BIVARIATE_POLYNOMIAL_STORAGE_KEY_PREFIX,
@@ -356,8 +353,8 @@ mod tests {
 
     type PrimeField = p384::Scalar;
     type Group = p384::ProjectivePoint;
-    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
-    type VerificationMatrix = vss::VerificationMatrix<Group>;
+    type BivariatePolynomial = api::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = api::VerificationMatrix<Group>;
 
     #[test]
     fn test_unique_seal_contexts() {
@@ -15,10 +15,9 @@ use oasis_core_runtime::{
     consensus::beacon::EpochTime,
 };
 
-use secret_sharing::{
-    churp::{SecretShare, VerifiableSecretShare},
-    poly::{scalar_from_bytes, scalar_to_bytes, EncryptedPoint, Polynomial},
-    vss::VerificationMatrix,
+use secret_sharing::api::{
+    scalar_from_bytes, scalar_to_bytes, EncryptedPoint, Polynomial, SecretShare,
+    VerifiableSecretShare, VerificationMatrix,
 };
 
 use crate::crypto::KeyPairId;
@@ -34,12 +34,7 @@ use oasis_core_runtime::{
     identity::Identity,
     protocol::Protocol,
 };
-use secret_sharing::{
-    churp::{HandoffKind, Player},
-    kdc::KeyRecoverer,
-    poly::EncryptedPoint,
-    suites::{p384, Suite},
-};
+use secret_sharing::api::{p384, EncryptedPoint, HandoffKind, KeyRecoverer, Player, Suite};
 
 use crate::{
     api::{
@@ -0,0 +1,68 @@
+//! Supported public API.
+//!
+//! This module re-exports the types, traits and functions that are meant
+//! to be used outside of this crate. The individual modules are private,
+//! so that their layout can change.
+
+pub use crate::{
+    churp::{
+        encode_shareholder, CommitteeChanged, CommitteeUnchanged, Dealer, DealingPhase,
+        Error as ChurpError, Handoff, HandoffKind, Player, SecretShare, Shareholder, SwitchPoint,
+        VerifiableSecretShare,
+    },
+    kdc::{KeyRecoverer, KeySharer, PointShareholder},
+    poly::{
+        lagrange::{coefficients, lagrange, lagrange_at_zero_ct},
+        scalar_from_bytes, scalar_to_bytes, BivariatePolynomial, EncryptedPoint, EvaluatorX, Point,
+        Polynomial, SecretBivariatePolynomial, EVAL_MANY_THRESHOLD,
+    },
+    suites::{p384, FieldDigest, GroupDigest, Suite},
+    vss::{
+        feldman, reconstruct, reconstruct_robust,
+        reconstruction::{assess, Assessment},
+        reshare, DecodeError, DecodeOptions, Error as VssError, Format as MatrixFormat,
+        GeneratorTable, MatrixCommitment, PartialMatrix, PedersenMatrix, PointEncoding,
+        PreparedVerificationMatrix, RowProof, Sec1Encoding, SecretCommitment, ShareId,
+        SubgroupCheck, UncompressedEncoding, VerificationContext, VerificationMatrix,
+        VerificationMatrixVerifier, VerificationMatrixView, VerificationVector,
+    },
+};
+
+#[cfg(feature = "cbor")]
+pub use crate::vss::MAX_CBOR_SIZE;
+
+/// Shamir secret sharing.
+pub mod shamir {
+    pub use crate::shamir::{Dealer, Player, Shareholder};
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{coefficients, lagrange, lagrange_at_zero_ct, shamir};
+
+    type PrimeField = p384::Scalar;
+
+    #[test]
+    fn test_shamir_through_facade() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = PrimeField::from_u64(100);
+        let xs: Vec<_> = (1..=4).map(PrimeField::from_u64).collect();
+
+        let dealer = shamir::Dealer::new(2, secret, &mut rng);
+        let shares = dealer.make_shares(xs.clone());
+
+        let player = shamir::Player::new(2);
+        let recovered = player
+            .recover_secret(&shares)
+            .expect("recovery should succeed");
+        assert_eq!(recovered, secret);
+
+        let points: Vec<_> = shares.iter().collect();
+        assert_eq!(lagrange(&points).eval(&PrimeField::ZERO), secret);
+
+        let cs = lagrange_at_zero_ct(&xs).expect("x-coordinates should be unique");
+        assert_eq!(cs[..], coefficients(&xs)[..]);
+    }
+}
@@ -1,7 +1,7 @@
 //! CHURP dealer.
 
 use anyhow::Result;
-use group::{ff::Field, Group};
+use group::{ff::Field, Group, GroupEncoding};
 use rand_core::RngCore;
 use zeroize::Zeroize;
 
@@ -105,6 +105,22 @@ where
         &self.vm
     }
 
+    /// Decodes the verification matrix of a dealing for the given threshold
+    /// from its byte representation.
+    ///
+    /// The degrees encoded in the header are checked against the degrees
+    /// of a dealing, i.e. `t` and `2t`, before any element is decoded,
+    /// so that an untrusted dealer cannot force other participants
+    /// to decode an arbitrarily large matrix.
+    pub fn decode_verification_matrix(threshold: u8, bytes: &[u8]) -> Result<VerificationMatrix<G>>
+    where
+        G: GroupEncoding,
+    {
+        let (deg_x, deg_y) = Self::degrees(threshold)?;
+        let vm = VerificationMatrix::try_from_bytes_bounded(bytes, deg_x.into(), deg_y.into())?;
+        Ok(vm)
+    }
+
     /// Generates shares of the secret for the given shareholders.
     pub fn make_shares(
         &self,
@@ -137,8 +153,7 @@ where
         threshold: u8,
         rng: &mut impl RngCore,
     ) -> Result<BivariatePolynomial<G::Scalar>> {
-        let deg_x = threshold;
-        let deg_y = threshold.checked_mul(2).ok_or(Error::ThresholdTooLarge)?;
+        let (deg_x, deg_y) = Self::degrees(threshold)?;
 
         // When using a random RNG and a large prime field, this loop
         // should execute once with an extremely high probability,
@@ -163,6 +178,13 @@ where
 
         Err(Error::PolynomialGenerationFailed.into())
     }
+
+    /// Returns the degrees of the bivariate polynomial of a dealing
+    /// for the given threshold.
+    fn degrees(threshold: u8) -> Result<(u8, u8)> {
+        let deg_y = threshold.checked_mul(2).ok_or(Error::ThresholdTooLarge)?;
+        Ok((threshold, deg_y))
+    }
 }
 
 impl<G> From<BivariatePolynomial<G::Scalar>> for Dealer<G>
@@ -283,6 +305,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_decode_verification_matrix() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let bytes = dealer.verification_matrix().to_bytes();
+
+        for threshold in [2, 3, 127] {
+            let vm = Dealer::decode_verification_matrix(threshold, &bytes).unwrap();
+            assert_eq!(&vm, dealer.verification_matrix());
+        }
+
+        // Degrees exceeding those of a dealing.
+        for threshold in [0, 1] {
+            let res = Dealer::decode_verification_matrix(threshold, &bytes);
+            assert!(res.is_err());
+        }
+
+        // Threshold too large.
+        let res = Dealer::decode_verification_matrix(128, &bytes);
+        assert!(res.is_err());
+
+        // Invalid input.
+        let res = Dealer::decode_verification_matrix(2, &bytes[1..]);
+        assert!(res.is_err());
+    }
+
     #[test]
     fn test_generate_bivariate_polynomial() {
         /// A custom RNG that fills the first few slices with zeros,
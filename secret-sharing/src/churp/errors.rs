@@ -32,6 +32,8 @@ pub enum Error {
     ShareholderIdentityMismatch,
     #[error("shareholder identity required")]
     ShareholderIdentityRequired,
+    #[error("threshold not increased")]
+    ThresholdNotIncreased,
     #[error("threshold too large")]
     ThresholdTooLarge,
     #[error("too many switch points")]
@@ -77,6 +77,55 @@ where
 
         Ok(verifiable_share.into())
     }
+
+    /// Creates a new shareholder with a proactivized secret polynomial
+    /// of a higher degree, effectively increasing the threshold without
+    /// changing the shared secret.
+    ///
+    /// The given polynomial and verification matrix must be derived from
+    /// a zero-hole bivariate polynomial generated for the new threshold.
+    /// The dimensions of the combined verification matrix, as well as
+    /// the size of the combined polynomial, must match the new threshold
+    /// exactly.
+    pub fn upgrade_threshold(
+        &self,
+        threshold: u8,
+        p: &Polynomial<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<Shareholder<G>> {
+        let (rows, cols) = self.verifiable_share.vm.dimensions();
+        if (threshold as usize) < rows {
+            return Err(Error::ThresholdNotIncreased.into());
+        }
+        if !vm.is_zero_hole() {
+            return Err(Error::VerificationMatrixZeroHoleMismatch.into());
+        }
+
+        // The new threshold is non-zero, so the verification matrix
+        // of the dealing is not square and the size of the polynomial
+        // determines the kind of the share unambiguously.
+        let full_share = p.size() == vm.dimensions().1;
+        let size = match full_share {
+            true => cols,
+            false => rows,
+        };
+        if self.verifiable_share.p.size() != size {
+            return Err(Error::PolynomialDegreeMismatch.into());
+        }
+
+        let x = self.verifiable_share.x;
+        let p = p + &self.verifiable_share.p;
+        let vm = vm + &self.verifiable_share.vm;
+        let share = SecretShare::new(x, p);
+        let verifiable_share = VerifiableSecretShare::new(share, vm);
+
+        // Ensure that the dimensions match the new threshold and that
+        // the combined bivariate polynomial satisfies the non-zero leading
+        // term requirements.
+        verifiable_share.verify(threshold, false, full_share)?;
+
+        Ok(verifiable_share.into())
+    }
 }
 
 impl<G> From<VerifiableSecretShare<G>> for Shareholder<G>
@@ -242,8 +291,8 @@ where
             // one coefficient. The verification of this coefficient has already
             // been done above, when we checked if the verification matrix
             // is zero-hole.
-            let i = rows - 1;
-            let j = cols - 1;
+            let i = self.vm.deg_x();
+            let j = self.vm.deg_y();
 
             if self.vm.element(i, j).unwrap().is_identity().into() {
                 return Err(Error::InsecureBivariatePolynomial.into());
@@ -324,3 +373,110 @@ where
         self.vm += &rhs.vm;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, HandoffKind, Player, SecretShare, VerifiableSecretShare},
+        suites::{self, p384},
+    };
+
+    use super::Error;
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Shareholder = churp::Shareholder<Group>;
+    type Dealer = churp::Dealer<Group>;
+
+    fn secret_shares(shareholders: &[Shareholder]) -> Vec<SecretShare<PrimeField>> {
+        shareholders
+            .iter()
+            .map(|sh| {
+                let share = sh.verifiable_share();
+                SecretShare::new(*share.x(), share.polynomial().clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_upgrade_threshold() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let kind = HandoffKind::DealingPhase;
+        let threshold = 2;
+        let new_threshold = 3;
+        let secret = PrimeField::from_u64(100);
+
+        // Share the secret among a committee of 5 members.
+        let dealer = Dealer::new_with_secret(threshold, secret, &mut rng).unwrap();
+        let xs: Vec<_> = (1..=5).map(PrimeField::from_u64).collect();
+        let shareholders: Vec<Shareholder> = dealer
+            .make_shares(xs, kind)
+            .into_iter()
+            .map(|share| VerifiableSecretShare::new(share, dealer.verification_matrix().clone()))
+            .map(Into::into)
+            .collect();
+
+        // Dealings of the same or lower degree are rejected.
+        let proactive = Dealer::new_proactive(threshold, &mut rng).unwrap();
+        let share = proactive.make_share(*shareholders[0].verifiable_share().x(), kind);
+        let res = shareholders[0].upgrade_threshold(
+            threshold,
+            share.polynomial(),
+            proactive.verification_matrix(),
+        );
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::ThresholdNotIncreased.to_string()
+        );
+
+        // Dealings that are not zero-hole are rejected.
+        let dealing = Dealer::new(new_threshold, &mut rng).unwrap();
+        let share = dealing.make_share(*shareholders[0].verifiable_share().x(), kind);
+        let res = shareholders[0].upgrade_threshold(
+            new_threshold,
+            share.polynomial(),
+            dealing.verification_matrix(),
+        );
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            Error::VerificationMatrixZeroHoleMismatch.to_string()
+        );
+
+        // Upgrade the threshold with a zero-hole dealing of higher degree.
+        let proactive = Dealer::new_proactive(new_threshold, &mut rng).unwrap();
+        let upgraded: Vec<_> = shareholders
+            .iter()
+            .map(|sh| {
+                let share = proactive.make_share(*sh.verifiable_share().x(), kind);
+                sh.upgrade_threshold(
+                    new_threshold,
+                    share.polynomial(),
+                    proactive.verification_matrix(),
+                )
+                .unwrap()
+            })
+            .collect();
+
+        for sh in upgraded.iter() {
+            let share = sh.verifiable_share();
+            assert_eq!(share.verification_matrix().dimensions(), (4, 7));
+            assert!(share.verify(new_threshold, false, true).is_ok());
+        }
+
+        // The secret is unchanged and requires more shares to be recovered.
+        let shares = secret_shares(&upgraded);
+
+        let player = Player::new(new_threshold, kind);
+        let recovered = player.recover_secret(&shares[..4]).unwrap();
+        assert_eq!(recovered, secret);
+        let recovered = player.recover_secret(&shares[1..]).unwrap();
+        assert_eq!(recovered, secret);
+
+        let player = Player::new(threshold, kind);
+        let recovered = player.recover_secret(&shares[..3]).unwrap();
+        assert_ne!(recovered, secret);
+    }
+}
@@ -708,6 +708,15 @@ mod tests {
                 Error::DuplicateShareholder.to_string()
             );
 
+            // Add another point with the same shareholder but a different value.
+            let point = SwitchPoint::new(prepare_shareholder(sh), prepare_shareholder(100));
+            let res = sp.add_point(point);
+            assert!(res.is_err());
+            assert_eq!(
+                res.unwrap_err().to_string(),
+                Error::DuplicateShareholder.to_string()
+            );
+
             assert!(!sp.needs_point(&prepare_shareholder(sh)));
             sh += 1;
 
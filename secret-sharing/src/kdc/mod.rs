@@ -59,19 +59,22 @@ pub trait KeyRecoverer {
     fn recover_key<G>(&self, shares: &[EncryptedPoint<G>]) -> Result<G>
     where
         G: Group + Zeroize,
+        G::Scalar: Zeroize,
     {
         if shares.len() < self.min_shares() {
             bail!("not enough shares");
         }
-        if !Self::distinct_shares(shares) {
-            bail!("not distinct shares");
-        }
 
+        // Compute the coefficients in constant time so that the time taken
+        // doesn't reveal which shareholders responded.
         let xs = shares.iter().map(|s| *s.x()).collect::<Vec<_>>();
-        let cs = lagrange::coefficients(&xs);
+        let cs = match lagrange::lagrange_at_zero_ct(&xs) {
+            Some(cs) => cs,
+            None => bail!("not distinct shares"),
+        };
         let mut key = G::identity();
 
-        for (ci, share) in cs.into_iter().zip(shares) {
+        for (ci, share) in cs.iter().zip(shares) {
             let mut zi = *share.z();
             zi *= ci;
             key += &zi;
@@ -80,18 +83,4 @@ pub trait KeyRecoverer {
 
         Ok(key)
     }
-
-    /// Returns true iff shares are from distinct shareholders.
-    fn distinct_shares<G: Group>(shares: &[EncryptedPoint<G>]) -> bool {
-        // For a small number of shareholders, a brute-force approach should
-        // suffice, and it doesn't require the prime field to be hashable.
-        for i in 0..shares.len() {
-            for j in (i + 1)..shares.len() {
-                if shares[i].x == shares[j].x {
-                    return false;
-                }
-            }
-        }
-        true
-    }
 }
@@ -9,11 +9,16 @@
 //! - CHURP (CHUrn-Robust Proactive secret sharing)
 //! - Shamir (Shamir secret sharing)
 
+#![feature(min_specialization)]
 #![feature(test)]
 
-pub mod churp;
-pub mod kdc;
-pub mod poly;
-pub mod shamir;
-pub mod suites;
-pub mod vss;
+// The supported public API is re-exported by the `api` module. The remaining
+// modules are private, so that their layout can change.
+
+pub mod api;
+mod churp;
+mod kdc;
+mod poly;
+mod shamir;
+mod suites;
+mod vss;
@@ -3,18 +3,29 @@ use group::ff::PrimeField;
 /// Returns a vector containing powers of x: x^0, x^1, ..., x^k.
 pub fn powers<F: PrimeField>(x: &F, k: usize) -> Vec<F> {
     let mut pows = Vec::with_capacity(k + 1);
+    powers_into(x, k, &mut pows);
+    pows
+}
+
+/// Clears the given vector and fills it with powers of x: x^0, x^1, ..., x^k.
+///
+/// Unlike [`powers`], this function reuses the allocation of the vector,
+/// which is useful when powers are computed repeatedly.
+pub fn powers_into<F: PrimeField>(x: &F, k: usize, pows: &mut Vec<F>) {
+    pows.clear();
+    pows.reserve(k + 1);
+
     let mut prev = F::ONE;
     for _ in 0..k {
         pows.push(prev);
         prev *= x;
     }
     pows.push(prev);
-    pows
 }
 
 #[cfg(test)]
 mod tests {
-    use super::powers;
+    use super::{powers, powers_into};
 
     type PrimeField = p384::Scalar;
 
@@ -31,4 +42,24 @@ mod tests {
         let xpows = powers(&x2, 4);
         assert_eq!(xpows, vec![PrimeField::ONE, x2, x4, x8, x16]);
     }
+
+    #[test]
+    fn test_powers_into() {
+        let x2 = PrimeField::from_u64(2);
+        let x4 = PrimeField::from_u64(4);
+        let x3 = PrimeField::from_u64(3);
+
+        let mut xpows = vec![x3; 10];
+        powers_into(&x2, 2, &mut xpows);
+        assert_eq!(xpows, vec![PrimeField::ONE, x2, x4]);
+
+        powers_into(&x2, 0, &mut xpows);
+        assert_eq!(xpows, vec![PrimeField::ONE]);
+
+        for k in 0..20 {
+            powers_into(&x3, k, &mut xpows);
+            assert_eq!(xpows.len(), k + 1);
+            assert_eq!(xpows, powers(&x3, k));
+        }
+    }
 }
@@ -1,9 +1,18 @@
+use std::{
+    cmp::max,
+    mem,
+    ops::{Deref, DerefMut, Mul, MulAssign, Sub, SubAssign},
+};
+
 use group::ff::PrimeField;
 use rand_core::RngCore;
 use subtle::{Choice, CtOption};
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
-use crate::poly::powers;
+use crate::poly::{
+    ops::{scale_slice, sub_assign_slices},
+    powers,
+};
 
 use super::Polynomial;
 
@@ -57,6 +66,60 @@ where
         Self { b, deg_x, deg_y }
     }
 
+    /// Creates a zero-hole bivariate polynomial with random coefficients,
+    /// i.e., a polynomial whose constant term `b_{0,0}` is zero.
+    ///
+    /// All other coefficients are distributed as in [`Self::random`].
+    ///
+    /// This method is not constant time as some prime field implementations
+    /// may generate uniformly random elements using rejection sampling.
+    pub fn random_with_zero_hole(deg_x: u8, deg_y: u8, rng: &mut impl RngCore) -> Self {
+        Self::random_with_secret(deg_x, deg_y, F::ZERO, rng)
+    }
+
+    /// Creates a bivariate polynomial with random coefficients whose
+    /// constant term `b_{0,0}` is the given secret.
+    ///
+    /// All other coefficients are distributed as in [`Self::random`].
+    ///
+    /// This method is not constant time as some prime field implementations
+    /// may generate uniformly random elements using rejection sampling.
+    pub fn random_with_secret(deg_x: u8, deg_y: u8, secret: F, rng: &mut impl RngCore) -> Self {
+        let mut bp = Self::random(deg_x, deg_y, rng);
+        bp.b[0][0] = secret;
+        bp
+    }
+
+    /// Creates a symmetric bivariate polynomial of the given degree in both
+    /// variables with random coefficients, i.e., a polynomial for which
+    /// `B(x,y) == B(y,x)`.
+    ///
+    /// Only the coefficients `b_{i,j}` with `i <= j` are sampled, the others
+    /// are mirrored so that `b_{j,i} == b_{i,j}`.
+    ///
+    /// This method is not constant time as some prime field implementations
+    /// may generate uniformly random elements using rejection sampling.
+    pub fn random_symmetric(deg: u8, rng: &mut impl RngCore) -> Self {
+        let deg = deg as usize;
+
+        let mut b: Vec<Vec<F>> = Vec::with_capacity(deg + 1);
+        for i in 0..=deg {
+            let mut bi = Vec::with_capacity(deg + 1);
+            bi.extend(b.iter().map(|bj| bj[i])); // b_{i,j} = b_{j,i} for j < i
+            for _ in i..=deg {
+                let bij = F::random(&mut *rng);
+                bi.push(bij);
+            }
+            b.push(bi);
+        }
+
+        Self {
+            b,
+            deg_x: deg,
+            deg_y: deg,
+        }
+    }
+
     /// Creates a bivariate polynomial with the given coefficients.
     ///
     /// # Panics
@@ -80,6 +143,28 @@ where
         Self { b, deg_x, deg_y }
     }
 
+    /// Creates a bivariate polynomial of the given degrees whose coefficients
+    /// are zero except for the given entries, where an entry `((i, j), bij)`
+    /// sets the coefficient `b_{i,j}` of the term `x^i y^j`.
+    ///
+    /// If an index appears more than once, the last entry wins.
+    ///
+    /// Returns `None` if an index exceeds the degrees of the polynomial.
+    pub fn from_sparse(
+        deg_x: u8,
+        deg_y: u8,
+        entries: impl IntoIterator<Item = ((usize, usize), F)>,
+    ) -> Option<Self> {
+        let mut bp = Self::zero(deg_x, deg_y);
+        for ((i, j), bij) in entries {
+            if !bp.set_coefficient(i, j, bij) {
+                return None;
+            }
+        }
+
+        Some(bp)
+    }
+
     /// Sets the coefficient `b_{i,j}` that belongs to the term `x^i y^j`.
     ///
     /// If the coefficient does not exist, this is a no-op.
@@ -105,6 +190,28 @@ where
         self.b[0][0].is_zero().into()
     }
 
+    /// Returns true iff the bivariate polynomial is symmetric, i.e., if it
+    /// holds `b_{i,j} == b_{j,i}` for all `i` and `j`.
+    ///
+    /// This method is not constant time if the degrees differ.
+    pub fn is_symmetric(&self) -> bool {
+        // Short-circuit on the degrees, not the coefficients.
+        if self.deg_x != self.deg_y {
+            return false;
+        }
+
+        // Don't short-circuit this loop to avoid revealing which coefficient
+        // differs.
+        let mut symmetric = Choice::from(1);
+        for (i, bi) in self.b.iter().enumerate() {
+            for (j, bij) in bi.iter().enumerate().skip(i + 1) {
+                symmetric &= bij.ct_eq(&self.b[j][i]);
+            }
+        }
+
+        symmetric.into()
+    }
+
     /// Returns the coefficient `b_{i,j}` of the bivariate polynomial.
     pub fn coefficient(&self, i: usize, j: usize) -> Option<&F> {
         self.b.get(i).and_then(|bi| bi.get(j))
@@ -243,6 +350,86 @@ where
 
         Polynomial::with_coefficients(a)
     }
+
+    /// Returns an evaluator which evaluates the bivariate polynomial with
+    /// respect to the indeterminate x at many points, as [`Self::eval_x`]
+    /// does.
+    pub fn evaluator_x(&self) -> EvaluatorX<F>
+    where
+        F: Zeroize,
+    {
+        EvaluatorX::new(self)
+    }
+}
+
+/// Evaluator of a bivariate polynomial with respect to the indeterminate x.
+///
+/// The evaluator stores the coefficients of the bivariate polynomial
+/// column by column, so that every coefficient of the resulting polynomial
+/// is computed with Horner's method over a contiguous slice, without
+/// computing the powers of `x`. The coefficients are zeroized when
+/// the evaluator is dropped.
+pub struct EvaluatorX<F>
+where
+    F: PrimeField + Zeroize,
+{
+    /// The coefficients of the bivariate polynomial, where `c[j][i]`
+    /// represents the coefficient of the term `x^i y^j`.
+    c: Vec<Vec<F>>,
+}
+
+impl<F> EvaluatorX<F>
+where
+    F: PrimeField + Zeroize,
+{
+    /// Creates a new evaluator for the given bivariate polynomial.
+    fn new(bp: &BivariatePolynomial<F>) -> Self {
+        let mut c = Vec::with_capacity(bp.deg_y + 1);
+        for j in 0..=bp.deg_y {
+            c.push(bp.b.iter().map(|bi| bi[j]).collect());
+        }
+
+        Self { c }
+    }
+
+    /// Evaluates the bivariate polynomial with respect to the indeterminate x.
+    ///
+    /// The returned polynomial is equal to the one returned
+    /// by [`BivariatePolynomial::eval_x`].
+    pub fn eval_x(&self, x: &F) -> Polynomial<F> {
+        let mut a = Vec::with_capacity(self.c.len());
+        for cj in self.c.iter() {
+            let mut aj = F::ZERO;
+            for bij in cj.iter().rev() {
+                aj = aj * x + bij // a_j = (...(b_{deg_x,j} x + b_{deg_x-1,j}) x + ...) x + b_{0,j}
+            }
+            a.push(aj)
+        }
+
+        Polynomial::with_coefficients(a)
+    }
+}
+
+impl<F> Zeroize for EvaluatorX<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn zeroize(&mut self) {
+        for cj in self.c.iter_mut() {
+            for cij in cj.iter_mut() {
+                cij.zeroize();
+            }
+        }
+    }
+}
+
+impl<F> Drop for EvaluatorX<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
 }
 
 impl<F> Zeroize for BivariatePolynomial<F>
@@ -258,15 +445,242 @@ where
     }
 }
 
+impl<F> Sub for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[inline]
+    fn sub(self, rhs: BivariatePolynomial<F>) -> BivariatePolynomial<F> {
+        &self - &rhs
+    }
+}
+
+impl<F> Sub<&BivariatePolynomial<F>> for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[inline]
+    fn sub(self, rhs: &BivariatePolynomial<F>) -> BivariatePolynomial<F> {
+        &self - rhs
+    }
+}
+
+impl<F> Sub<BivariatePolynomial<F>> for &BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[inline]
+    fn sub(self, rhs: BivariatePolynomial<F>) -> BivariatePolynomial<F> {
+        self - &rhs
+    }
+}
+
+impl<F> Sub for &BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    fn sub(self, rhs: &BivariatePolynomial<F>) -> BivariatePolynomial<F> {
+        let deg_x = max(self.deg_x, rhs.deg_x);
+        let deg_y = max(self.deg_y, rhs.deg_y);
+        let mut b = Vec::with_capacity(deg_x + 1);
+
+        for i in 0..=deg_x {
+            let mut bi = Vec::with_capacity(deg_y + 1);
+            if let Some(ai) = self.b.get(i) {
+                bi.extend_from_slice(ai);
+            }
+            bi.resize(deg_y + 1, F::ZERO);
+
+            if let Some(ri) = rhs.b.get(i) {
+                sub_assign_slices(&mut bi[..ri.len()], ri);
+            }
+
+            b.push(bi);
+        }
+
+        BivariatePolynomial { deg_x, deg_y, b }
+    }
+}
+
+impl<F> SubAssign for BivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: BivariatePolynomial<F>) {
+        *self -= &rhs
+    }
+}
+
+impl<F> SubAssign<&BivariatePolynomial<F>> for BivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn sub_assign(&mut self, rhs: &BivariatePolynomial<F>) {
+        if self.deg_x < rhs.deg_x || self.deg_y < rhs.deg_y {
+            // Zeroize the old coefficients, as they don't fit.
+            let mut bp = &*self - rhs;
+            mem::swap(self, &mut bp);
+            bp.zeroize();
+            return;
+        }
+
+        for (bi, ri) in self.b.iter_mut().zip(rhs.b.iter()) {
+            sub_assign_slices(&mut bi[..ri.len()], ri);
+        }
+    }
+}
+
+impl<F> Mul<F> for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[inline]
+    fn mul(mut self, scalar: F) -> BivariatePolynomial<F> {
+        self *= &scalar;
+        self
+    }
+}
+
+impl<F> Mul<&F> for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[inline]
+    fn mul(mut self, scalar: &F) -> BivariatePolynomial<F> {
+        self *= scalar;
+        self
+    }
+}
+
+impl<F> Mul<F> for &BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[allow(clippy::op_ref)]
+    #[inline]
+    fn mul(self, scalar: F) -> BivariatePolynomial<F> {
+        self * &scalar
+    }
+}
+
+impl<F> Mul<&F> for &BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    fn mul(self, scalar: &F) -> BivariatePolynomial<F> {
+        self.clone() * scalar
+    }
+}
+
+impl<F> MulAssign<F> for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    #[inline]
+    fn mul_assign(&mut self, scalar: F) {
+        *self *= &scalar
+    }
+}
+
+impl<F> MulAssign<&F> for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    fn mul_assign(&mut self, scalar: &F) {
+        for bi in self.b.iter_mut() {
+            scale_slice(bi, scalar);
+        }
+    }
+}
+
+/// A simple wrapper around bivariate polynomial that is zeroized when
+/// dropped.
+///
+/// Dealers should hold their secret polynomials in this wrapper so that
+/// the coefficients don't linger in memory, not even on early returns.
+pub struct SecretBivariatePolynomial<F>(BivariatePolynomial<F>)
+where
+    F: PrimeField + Zeroize;
+
+impl<F> SecretBivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    /// Creates a new secret bivariate polynomial.
+    pub fn new(bp: BivariatePolynomial<F>) -> Self {
+        Self(bp)
+    }
+}
+
+impl<F> Deref for SecretBivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    type Target = BivariatePolynomial<F>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<F> DerefMut for SecretBivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<F> Zeroize for SecretBivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<F> Drop for SecretBivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<F> ZeroizeOnDrop for SecretBivariatePolynomial<F> where F: PrimeField + Zeroize {}
+
 #[cfg(test)]
 mod tests {
     use std::panic;
 
     use rand::{rngs::StdRng, SeedableRng};
+    use zeroize::Zeroize;
 
     type PrimeField = p384::Scalar;
     type Polynomial = super::Polynomial<PrimeField>;
     type BivariatePolynomial = super::BivariatePolynomial<PrimeField>;
+    type SecretBivariatePolynomial = super::SecretBivariatePolynomial<PrimeField>;
+    type VerificationMatrix = crate::vss::VerificationMatrix<p384::ProjectivePoint>;
 
     fn scalar(value: i64) -> PrimeField {
         scalars(&vec![value])[0]
@@ -328,6 +742,25 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_from_sparse() {
+        let bp = BivariatePolynomial::from_sparse(1, 2, []).unwrap();
+        assert_eq!(bp.deg_x, 1);
+        assert_eq!(bp.deg_y, 2);
+        assert_eq!(bp.b, vec![scalars(&[0, 0, 0]), scalars(&[0, 0, 0])]);
+
+        let entries = [
+            ((0, 1), scalar(5)),
+            ((1, 2), scalar(7)),
+            ((0, 1), scalar(3)),
+        ];
+        let bp = BivariatePolynomial::from_sparse(1, 2, entries).unwrap();
+        assert_eq!(bp.b, vec![scalars(&[0, 3, 0]), scalars(&[0, 0, 7])]);
+
+        assert!(BivariatePolynomial::from_sparse(1, 2, [((2, 0), scalar(1))]).is_none());
+        assert!(BivariatePolynomial::from_sparse(1, 2, [((0, 3), scalar(1))]).is_none());
+    }
+
     #[test]
     fn test_random() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -350,6 +783,136 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_random_with_secret() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = scalar(42);
+
+        let bp = BivariatePolynomial::random_with_secret(2, 3, secret, &mut rng);
+        assert_eq!(bp.deg_x, 2);
+        assert_eq!(bp.deg_y, 3);
+        assert_eq!(bp.b[0][0], secret);
+        assert_eq!(bp.eval(&scalar(0), &scalar(0)), secret);
+        for (i, bi) in bp.b.iter().enumerate() {
+            assert_eq!(bi.len(), 4);
+            for bij in bi.iter().skip((i == 0) as usize) {
+                assert_ne!(bij, &scalar(0)); // Zero with negligible probability.
+            }
+        }
+
+        let bp = BivariatePolynomial::random_with_secret(0, 0, secret, &mut rng);
+        assert!(bp == BivariatePolynomial::with_coefficients(vec![vec![secret]]));
+
+        // Other coefficients are sampled as by random.
+        let mut rng1: StdRng = SeedableRng::from_seed([2u8; 32]);
+        let mut rng2: StdRng = SeedableRng::from_seed([2u8; 32]);
+        let mut bp = BivariatePolynomial::random(2, 3, &mut rng1);
+        assert!(bp.set_coefficient(0, 0, secret));
+        assert!(bp == BivariatePolynomial::random_with_secret(2, 3, secret, &mut rng2));
+    }
+
+    #[test]
+    fn test_random_with_zero_hole() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (deg_x, deg_y) in [(0, 0), (2, 3), (3, 2)] {
+            let bp = BivariatePolynomial::random_with_zero_hole(deg_x, deg_y, &mut rng);
+            assert_eq!(bp.deg_x, deg_x as usize);
+            assert_eq!(bp.deg_y, deg_y as usize);
+            assert!(bp.is_zero_hole());
+            assert_eq!(bp.eval(&scalar(0), &scalar(0)), scalar(0));
+
+            let vm = VerificationMatrix::from(&bp);
+            assert!(vm.is_zero_hole());
+        }
+
+        let bp = BivariatePolynomial::random_with_zero_hole(2, 3, &mut rng);
+        assert_ne!(bp.b[0][1], scalar(0)); // Zero with negligible probability.
+        assert_ne!(bp.b[1][0], scalar(0)); // Zero with negligible probability.
+    }
+
+    #[test]
+    fn test_random_symmetric() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random_symmetric(0, &mut rng);
+        assert_eq!(bp.deg_x, 0);
+        assert_eq!(bp.deg_y, 0);
+        assert_ne!(bp.b[0][0], scalar(0)); // Zero with negligible probability.
+        assert!(bp.is_symmetric());
+
+        let bp = BivariatePolynomial::random_symmetric(3, &mut rng);
+        assert_eq!(bp.deg_x, 3);
+        assert_eq!(bp.deg_y, 3);
+        assert_eq!(bp.b.len(), 4);
+        for (i, bi) in bp.b.iter().enumerate() {
+            assert_eq!(bi.len(), 4);
+            for (j, bij) in bi.iter().enumerate() {
+                assert_ne!(bij, &scalar(0)); // Zero with negligible probability.
+                assert_eq!(bij, &bp.b[j][i]);
+            }
+        }
+        assert!(bp.is_symmetric());
+
+        let x = scalar(2);
+        let y = scalar(3);
+        assert_eq!(bp.eval(&x, &y), bp.eval(&y, &x));
+        assert!(bp.eval_x(&x) == bp.eval_y(&x));
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        let test_cases = vec![
+            (vec![scalars(&[1])], true),
+            (vec![scalars(&[1, 2]), scalars(&[2, 3])], true),
+            (vec![scalars(&[1, 2]), scalars(&[3, 4])], false),
+            (
+                vec![
+                    scalars(&[1, 2, 3]),
+                    scalars(&[2, 4, 5]),
+                    scalars(&[3, 5, 6]),
+                ],
+                true,
+            ),
+            (
+                vec![
+                    scalars(&[1, 2, 3]),
+                    scalars(&[2, 4, 5]),
+                    scalars(&[3, 6, 6]),
+                ],
+                false,
+            ),
+            (vec![scalars(&[1, 2, 3]), scalars(&[2, 4, 5])], false),
+            (vec![scalars(&[1]), scalars(&[1])], false),
+        ];
+
+        for (b, symmetric) in test_cases {
+            let bp = BivariatePolynomial::with_coefficients(b);
+            assert_eq!(bp.is_symmetric(), symmetric);
+        }
+
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(3, 3, &mut rng);
+        assert!(!bp.is_symmetric());
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut bp = BivariatePolynomial::random(2, 3, &mut rng);
+        bp.zeroize();
+        assert_eq!(bp.deg_x, 2);
+        assert_eq!(bp.deg_y, 3);
+        assert!(bp == BivariatePolynomial::zero(2, 3));
+
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let mut sbp = SecretBivariatePolynomial::new(bp.clone());
+        assert!(*sbp == bp);
+        assert!(sbp.set_coefficient(0, 0, scalar(1)));
+        assert_eq!(sbp.coefficient(0, 0), Some(&scalar(1)));
+        sbp.zeroize();
+        assert!(*sbp == BivariatePolynomial::zero(2, 3));
+    }
+
     #[test]
     fn test_set_coefficient() {
         let mut bp = BivariatePolynomial::zero(2, 3);
@@ -462,4 +1025,92 @@ mod tests {
         let expected = Polynomial::with_coefficients(scalars(&[586, 242, 298]));
         assert!(result == expected);
     }
+
+    #[test]
+    fn test_evaluator_x() {
+        let b = vec![
+            scalars(&[1, 2, 3, 4]),
+            scalars(&[2, 3, 4, 1]),
+            scalars(&[3, 4, 1, 2]),
+        ];
+        let bp = BivariatePolynomial::with_coefficients(b);
+        let evaluator = bp.evaluator_x();
+        let result = evaluator.eval_x(&scalar(5));
+        let expected = Polynomial::with_coefficients(scalars(&[86, 117, 48, 59]));
+        assert!(result == expected);
+
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        for (deg_x, deg_y) in [(0, 0), (0, 3), (3, 0), (2, 5), (5, 2)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let evaluator = bp.evaluator_x();
+            for x in scalars(&[0, 1, 2, -1, 12345]) {
+                assert!(evaluator.eval_x(&x) == bp.eval_x(&x));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sub() {
+        let a =
+            BivariatePolynomial::with_coefficients(vec![scalars(&[1, 2, 3]), scalars(&[4, 5, 6])]);
+        let b = BivariatePolynomial::with_coefficients(vec![
+            scalars(&[1, 1]),
+            scalars(&[1, 1]),
+            scalars(&[1, 1]),
+        ]);
+
+        let expected = BivariatePolynomial::with_coefficients(vec![
+            scalars(&[0, 1, 3]),
+            scalars(&[3, 4, 6]),
+            scalars(&[-1, -1, 0]),
+        ]);
+        assert!(&a - &b == expected);
+        assert!(a.clone() - &b == expected);
+        assert!(&a - b.clone() == expected);
+        assert!(a.clone() - b.clone() == expected);
+
+        let mut c = a.clone();
+        c -= &b;
+        assert!(c == expected);
+
+        let expected = BivariatePolynomial::with_coefficients(vec![
+            scalars(&[0, -1, -3]),
+            scalars(&[-3, -4, -6]),
+            scalars(&[1, 1, 0]),
+        ]);
+        let mut c = b.clone();
+        c -= a.clone();
+        assert!(c == expected);
+        assert!(&b - &a == expected);
+
+        // Subtracting from itself yields zero.
+        let mut c = a.clone();
+        c -= &a;
+        assert!(c == BivariatePolynomial::zero(1, 2));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_mul() {
+        let a =
+            BivariatePolynomial::with_coefficients(vec![scalars(&[1, 2, 3]), scalars(&[4, 5, 6])]);
+        let s = scalar(3);
+        let expected = BivariatePolynomial::with_coefficients(vec![
+            scalars(&[3, 6, 9]),
+            scalars(&[12, 15, 18]),
+        ]);
+
+        assert!(&a * &s == expected);
+        assert!(&a * s == expected);
+        assert!(a.clone() * &s == expected);
+        assert!(a.clone() * s == expected);
+
+        let mut c = a.clone();
+        c *= &s;
+        assert!(c == expected);
+
+        let mut c = a;
+        c *= s;
+        assert!(c == expected);
+    }
 }
@@ -0,0 +1,381 @@
+//! Fast polynomial arithmetic over prime fields with large two-adicity.
+//!
+//! Polynomials are represented by their coefficients in ascending order.
+//! The fast Fourier transform requires a root of unity whose order is a power
+//! of two at least as large as the transform, so the fast methods are only
+//! available in fields with a sufficiently large two-adicity `S`. Otherwise,
+//! and for small inputs, the naive methods are used.
+
+use std::cmp::min;
+
+use group::ff::PrimeField;
+
+use super::ops::axpy;
+
+/// The size of the smaller operand below which polynomials are multiplied
+/// naively.
+const NAIVE_MUL_THRESHOLD: usize = 64;
+
+/// Returns the size of the fast Fourier transform needed for polynomials
+/// with the given number of coefficients, or `None` if the field doesn't
+/// have a root of unity of that order.
+pub(crate) fn fft_size<F: PrimeField>(len: usize) -> Option<usize> {
+    let size = len.checked_next_power_of_two()?;
+    match size.trailing_zeros() <= F::S {
+        true => Some(size),
+        false => None,
+    }
+}
+
+/// Computes the (inverse) fast Fourier transform of the given coefficients
+/// in place, using the iterative radix-2 Cooley-Tukey algorithm.
+///
+/// # Panics
+///
+/// Panics if the length is not a power of two supported by the field.
+pub(crate) fn fft<F: PrimeField>(a: &mut [F], inverse: bool) {
+    let n = a.len();
+    assert!(
+        fft_size::<F>(n) == Some(n),
+        "length should be a supported power of two"
+    );
+
+    // Bit-reversal permutation.
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i
+            .reverse_bits()
+            .checked_shr(usize::BITS - bits)
+            .unwrap_or(0);
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut w_n = match inverse {
+        false => F::ROOT_OF_UNITY,
+        true => F::ROOT_OF_UNITY_INV,
+    };
+    for _ in bits..F::S {
+        w_n = w_n.square(); // The primitive root of unity of order n.
+    }
+
+    // The twiddle factors w_n^k for k < n/2, shared by all levels.
+    let mut twiddles = Vec::with_capacity(n / 2);
+    let mut w = F::ONE;
+    for _ in 0..n / 2 {
+        twiddles.push(w);
+        w *= w_n;
+    }
+
+    let mut len = 2;
+    while len <= n {
+        // The level uses the powers of the root of unity of order len.
+        let step = n / len;
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let ws = twiddles.iter().step_by(step);
+            for ((u, v), w) in lo.iter_mut().zip(hi.iter_mut()).zip(ws) {
+                let t = *v * w;
+                *v = *u - t;
+                *u += t;
+            }
+        }
+
+        len <<= 1;
+    }
+
+    if inverse {
+        let mut n_inv = F::ONE;
+        for _ in 0..bits {
+            n_inv *= F::TWO_INV;
+        }
+        for ai in a.iter_mut() {
+            *ai *= n_inv;
+        }
+    }
+}
+
+/// Returns the product of the given polynomials.
+///
+/// The fast Fourier transform is used if both polynomials are large enough
+/// and the field supports it.
+pub(crate) fn mul<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let len = a.len() + b.len() - 1;
+    let size = match fft_size::<F>(len) {
+        Some(size) if min(a.len(), b.len()) >= NAIVE_MUL_THRESHOLD => size,
+        _ => return mul_naive(a, b),
+    };
+
+    let mut fa = a.to_vec();
+    fa.resize(size, F::ZERO);
+    let mut fb = b.to_vec();
+    fb.resize(size, F::ZERO);
+
+    fft(&mut fa, false);
+    fft(&mut fb, false);
+    for (ai, bi) in fa.iter_mut().zip(fb.iter()) {
+        *ai *= bi;
+    }
+    fft(&mut fa, true);
+
+    fa.truncate(len);
+    fa
+}
+
+/// Returns the product of the given monic polynomials of equal degree.
+///
+/// The leading coefficients are handled separately so that the transform
+/// doesn't need to be twice as large when the degree is a power of two.
+pub(crate) fn mul_monic<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    debug_assert_eq!(a.len(), b.len(), "degrees should be equal");
+    debug_assert!(a.last() == Some(&F::ONE) && b.last() == Some(&F::ONE));
+
+    // (x^k + a') * (x^k + b') = x^2k + x^k * (a' + b') + a' * b'
+    let k = a.len() - 1;
+    let mut c = mul(&a[..k], &b[..k]);
+    c.resize(2 * k + 1, F::ZERO);
+    for (ci, (ai, bi)) in c[k..].iter_mut().zip(a.iter().zip(b.iter())) {
+        *ci += *ai + bi;
+    }
+    c[2 * k] = F::ONE;
+
+    c
+}
+
+/// Returns the product of the given polynomials, computed naively.
+fn mul_naive<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut c = vec![F::ZERO; a.len() + b.len() - 1];
+    for (i, ai) in a.iter().enumerate() {
+        axpy(&mut c[i..i + b.len()], ai, b);
+    }
+    c
+}
+
+/// Returns the inverse of the given polynomial modulo `x^k`, computed
+/// with Newton iteration, or `None` if its constant term is zero.
+pub(crate) fn inverse<F: PrimeField>(a: &[F], k: usize) -> Option<Vec<F>> {
+    let a0_inv: Option<F> = a.first()?.invert().into();
+    let mut g = vec![a0_inv?];
+
+    // Double the precision in every step: g = g * (2 - a * g) mod x^m.
+    let mut m = 1;
+    while m < k {
+        m = min(2 * m, k);
+        let mut t = mul(&a[..min(a.len(), m)], &g);
+        t.truncate(m);
+        for ti in t.iter_mut() {
+            *ti = -*ti;
+        }
+        t[0] += F::ONE.double();
+        g = mul(&g, &t);
+        g.truncate(m);
+    }
+
+    g.truncate(k);
+    Some(g)
+}
+
+/// Returns the remainder of the division of the first polynomial
+/// by the second, monic polynomial.
+///
+/// The remainder has exactly one coefficient less than the divisor.
+///
+/// # Panics
+///
+/// Panics if the divisor is not monic.
+pub(crate) fn rem<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    assert!(
+        b.last() == Some(&F::ONE),
+        "divisor should be a monic polynomial"
+    );
+
+    let m = b.len() - 1; // The degree of the divisor.
+    if a.len() <= m {
+        let mut r = a.to_vec();
+        r.resize(m, F::ZERO);
+        return r;
+    }
+
+    let n = a.len() - 1; // The degree of the dividend.
+    let k = n - m + 1; // The number of coefficients of the quotient.
+
+    let q = match fft_size::<F>(a.len()) {
+        Some(_) if min(k, m) >= NAIVE_MUL_THRESHOLD => {
+            // The reversed quotient is the reversed dividend multiplied
+            // by the inverse of the reversed divisor modulo x^k.
+            let a_rev: Vec<_> = a.iter().rev().take(k).copied().collect();
+            let b_rev: Vec<_> = b.iter().rev().copied().collect();
+            let b_rev_inv = inverse(&b_rev, k).expect("divisor should be monic");
+            let mut q_rev = mul(&a_rev, &b_rev_inv);
+            q_rev.truncate(k);
+            q_rev.reverse();
+            q_rev
+        }
+        _ => return rem_naive(a, b),
+    };
+
+    // r = a - b * q mod x^m.
+    let bq = mul(&b[..m], &q[..min(q.len(), m)]);
+    let mut r = a[..m].to_vec();
+    for (ri, bqi) in r.iter_mut().zip(bq.iter()) {
+        *ri -= bqi;
+    }
+
+    r
+}
+
+/// Returns the remainder of the division of the first polynomial
+/// by the second, monic polynomial, computed with long division.
+fn rem_naive<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let m = b.len() - 1;
+    let mut r = a.to_vec();
+    for i in (m..r.len()).rev() {
+        let c = -r[i];
+        axpy(&mut r[i - m..=i], &c, b);
+    }
+    r.resize(m, F::ZERO);
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::{Field, PrimeField as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{fft, fft_size, inverse, mul, mul_monic, mul_naive, rem, rem_naive};
+
+    type PrimeField = bls12_381::Scalar;
+
+    fn random_scalars(n: usize, rng: &mut StdRng) -> Vec<PrimeField> {
+        (0..n).map(|_| PrimeField::random(&mut *rng)).collect()
+    }
+
+    #[test]
+    fn test_fft_size() {
+        assert_eq!(fft_size::<PrimeField>(1), Some(1));
+        assert_eq!(fft_size::<PrimeField>(5), Some(8));
+        assert_eq!(fft_size::<PrimeField>(1 << 32), Some(1 << 32));
+        assert_eq!(fft_size::<PrimeField>((1 << 32) + 1), None);
+
+        // P-384 has two-adicity one.
+        assert_eq!(fft_size::<p384::Scalar>(2), Some(2));
+        assert_eq!(fft_size::<p384::Scalar>(3), None);
+    }
+
+    #[test]
+    fn test_fft() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for n in [1, 2, 4, 8, 64] {
+            let a = random_scalars(n, &mut rng);
+
+            // The transform evaluates the polynomial at the powers
+            // of the root of unity.
+            let mut w = PrimeField::ROOT_OF_UNITY;
+            for _ in n.trailing_zeros()..PrimeField::S {
+                w = w.square();
+            }
+            let mut fa = a.clone();
+            fft(&mut fa, false);
+            let mut wi = PrimeField::ONE;
+            for fai in fa.iter() {
+                let mut v = PrimeField::ZERO;
+                for aj in a.iter().rev() {
+                    v = v * wi + aj;
+                }
+                assert_eq!(*fai, v);
+                wi *= w;
+            }
+
+            // Round trip.
+            fft(&mut fa, true);
+            assert_eq!(fa, a);
+        }
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (n, m) in [(1, 1), (3, 5), (64, 64), (65, 100), (100, 65), (128, 128)] {
+            let a = random_scalars(n, &mut rng);
+            let b = random_scalars(m, &mut rng);
+            let c = mul(&a, &b);
+            assert_eq!(c.len(), n + m - 1);
+            assert_eq!(c, mul_naive(&a, &b));
+        }
+
+        assert!(mul::<PrimeField>(&[], &[PrimeField::ONE]).is_empty());
+    }
+
+    #[test]
+    fn test_mul_monic() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for n in [1, 2, 63, 64, 65, 128] {
+            let mut a = random_scalars(n, &mut rng);
+            a.push(PrimeField::ONE);
+            let mut b = random_scalars(n, &mut rng);
+            b.push(PrimeField::ONE);
+            assert_eq!(mul_monic(&a, &b), mul_naive(&a, &b));
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (n, k) in [(1, 1), (5, 3), (40, 40), (40, 100), (100, 200), (200, 130)] {
+            let a = random_scalars(n, &mut rng);
+            let g = inverse(&a, k).expect("inverse should exist");
+            assert_eq!(g.len(), k);
+
+            let mut ag = mul(&a, &g);
+            ag.resize(k, PrimeField::ZERO);
+            ag.truncate(k);
+            assert_eq!(ag[0], PrimeField::ONE);
+            assert!(ag[1..].iter().all(|c| *c == PrimeField::ZERO));
+        }
+
+        let a = vec![PrimeField::ZERO, PrimeField::ONE];
+        assert_eq!(inverse(&a, 4), None);
+        assert_eq!(inverse::<PrimeField>(&[], 4), None);
+    }
+
+    #[test]
+    fn test_rem() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (n, m) in [
+            (1, 1),
+            (1, 3),
+            (5, 3),
+            (100, 40),
+            (200, 64),
+            (300, 100),
+            (64, 200),
+        ] {
+            let a = random_scalars(n, &mut rng);
+            let mut b = random_scalars(m, &mut rng);
+            b.push(PrimeField::ONE);
+
+            let r = rem(&a, &b);
+            assert_eq!(r.len(), m);
+            assert_eq!(r, rem_naive(&a, &b));
+
+            // The remainder agrees with the dividend at the roots
+            // of the divisor, so check a - r is divisible by b.
+            let mut d = a.clone();
+            d.resize(d.len().max(m), PrimeField::ZERO);
+            for (di, ri) in d.iter_mut().zip(r.iter()) {
+                *di -= ri;
+            }
+            assert!(rem(&d, &b).iter().all(|c| *c == PrimeField::ZERO));
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use group::ff::PrimeField;
+use subtle::{Choice, CtOption};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Returns Lagrange coefficients for the given set of x-coordinates,
+/// or `None` if the x-coordinates are not unique.
+///
+/// The i-th Lagrange coefficient is defined as:
+/// ```text
+/// L_i(0) = \prod_{j=0,j≠i}^n x_j / (x_j - x_i)
+/// ```
+///
+/// Unlike [`super::coefficients`], this method performs the same sequence
+/// of operations for every set of x-coordinates of the same size, so that
+/// the time it takes doesn't reveal which shareholders participated.
+/// Duplicates are detected by comparing all pairs of x-coordinates
+/// and are only reported once all coefficients have been computed.
+pub fn lagrange_at_zero_ct<F>(xs: &[F]) -> Option<Zeroizing<Vec<F>>>
+where
+    F: PrimeField + Zeroize,
+{
+    let n = xs.len();
+
+    // Don't short-circuit the comparison to avoid revealing which
+    // x-coordinates are equal.
+    let mut distinct = Choice::from(1);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            distinct &= !xs[i].ct_eq(&xs[j]);
+        }
+    }
+
+    let mut cs = Zeroizing::new(Vec::with_capacity(n));
+    for i in 0..n {
+        let mut nom = F::ONE;
+        let mut denom = F::ONE;
+        for j in 0..n {
+            if j == i {
+                continue;
+            }
+            nom *= xs[j]; // x_j
+            denom *= xs[j] - xs[i]; // (x_j - x_i)
+        }
+
+        // The denominator is zero only if the x-coordinates are not unique,
+        // in which case the coefficient is set to zero and discarded below.
+        let denom_inv = denom.invert();
+        distinct &= denom_inv.is_some();
+        nom *= denom_inv.unwrap_or(F::ZERO); // L_i(0) = nom / denom
+        cs.push(nom);
+    }
+
+    CtOption::new(cs, distinct).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{super::coefficients, lagrange_at_zero_ct};
+
+    type PrimeField = p384::Scalar;
+
+    fn scalars(values: &[i64]) -> Vec<PrimeField> {
+        values
+            .iter()
+            .map(|&w| match w.is_negative() {
+                false => PrimeField::from_u64(w as u64),
+                true => PrimeField::from_u64(-w as u64).neg(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lagrange_at_zero_ct() {
+        // Empty set.
+        let cs = lagrange_at_zero_ct::<PrimeField>(&[]).expect("coefficients should exist");
+        assert!(cs.is_empty());
+
+        // Small sets.
+        let vec = [
+            scalars(&[1]),
+            scalars(&[1, 2, 3]),
+            scalars(&[-5, 7, 2, 11]),
+            scalars(&(1..=50).collect::<Vec<_>>()),
+        ];
+        for xs in vec {
+            let cs = lagrange_at_zero_ct(&xs).expect("coefficients should exist");
+            assert_eq!(*cs, coefficients(&xs));
+        }
+
+        // Random sets.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        for n in 1..=10 {
+            let xs: Vec<_> = (0..n).map(|_| PrimeField::random(&mut rng)).collect();
+            let cs = lagrange_at_zero_ct(&xs).expect("coefficients should exist");
+            assert_eq!(*cs, coefficients(&xs));
+        }
+
+        // Duplicates.
+        let vec = [
+            scalars(&[1, 1]),
+            scalars(&[1, 2, 3, 2]),
+            scalars(&[0, 5, 0]),
+        ];
+        for xs in vec {
+            assert!(lagrange_at_zero_ct(&xs).is_none());
+        }
+    }
+}
@@ -1,8 +1,10 @@
 //! Lagrange interpolation.
 
+mod constant_time;
 mod multiplier;
+#[cfg(test)]
 mod naive;
 mod optimized;
 
 // Re-exports.
-pub use self::{naive::*, optimized::*};
+pub use self::{constant_time::*, optimized::*};
@@ -43,7 +43,7 @@ where
 /// # Panics
 ///
 /// Panics if the x-coordinates are not unique.
-fn basis_polynomials<F: PrimeField>(xs: &[F]) -> Vec<Polynomial<F>> {
+pub(crate) fn basis_polynomials<F: PrimeField>(xs: &[F]) -> Vec<Polynomial<F>> {
     let m = multiplier_for_basis_polynomials(xs);
     (0..xs.len()).map(|i| basis_polynomial(xs, i, &m)).collect()
 }
@@ -8,7 +8,9 @@
 
 mod arith;
 mod bivariate;
+pub(crate) mod fft;
 pub mod lagrange;
+pub(crate) mod ops;
 mod point;
 mod scalar;
 mod univariate;
@@ -0,0 +1,171 @@
+//! Slice-based arithmetic shared by polynomial and matrix operations.
+//!
+//! The helpers operate on whole slices without per-element branching,
+//! which keeps the loops simple enough for the compiler to unroll
+//! and vectorize.
+
+use std::ops::{AddAssign, Mul, MulAssign, SubAssign};
+
+/// Adds the elements of `b` to the elements of `a`, i.e., `a_i += b_i`.
+///
+/// # Panics
+///
+/// Panics if the slices are not of equal length.
+pub(crate) fn add_assign_slices<T>(a: &mut [T], b: &[T])
+where
+    T: Copy + AddAssign,
+{
+    assert_eq!(a.len(), b.len(), "slices should be of equal length");
+
+    for (ai, bi) in a.iter_mut().zip(b) {
+        *ai += *bi;
+    }
+}
+
+/// Subtracts the elements of `b` from the elements of `a`, i.e., `a_i -= b_i`.
+///
+/// # Panics
+///
+/// Panics if the slices are not of equal length.
+pub(crate) fn sub_assign_slices<T>(a: &mut [T], b: &[T])
+where
+    T: Copy + SubAssign,
+{
+    assert_eq!(a.len(), b.len(), "slices should be of equal length");
+
+    for (ai, bi) in a.iter_mut().zip(b) {
+        *ai -= *bi;
+    }
+}
+
+/// Multiplies the elements of `a` by the given scalar, i.e., `a_i *= c`.
+pub(crate) fn scale_slice<T, S>(a: &mut [T], c: &S)
+where
+    T: MulAssign<S>,
+    S: Copy,
+{
+    for ai in a.iter_mut() {
+        *ai *= *c;
+    }
+}
+
+/// Adds the elements of `b` multiplied by the given scalar to the elements
+/// of `a`, i.e., `a_i += c * b_i`.
+///
+/// # Panics
+///
+/// Panics if the slices are not of equal length.
+pub(crate) fn axpy<T, S>(a: &mut [T], c: &S, b: &[T])
+where
+    T: Copy + AddAssign + Mul<S, Output = T>,
+    S: Copy,
+{
+    assert_eq!(a.len(), b.len(), "slices should be of equal length");
+
+    for (ai, bi) in a.iter_mut().zip(b) {
+        *ai += *bi * *c;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+
+    use super::{add_assign_slices, axpy, scale_slice, sub_assign_slices};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    fn random_scalars(n: usize, mut rng: &mut impl RngCore) -> Vec<PrimeField> {
+        (0..n).map(|_| PrimeField::random(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_slices() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for _ in 0..20 {
+            let n = rng.gen_range(0..20);
+            let a = random_scalars(n, &mut rng);
+            let b = random_scalars(n, &mut rng);
+            let c = PrimeField::random(&mut rng);
+
+            let mut s = a.clone();
+            add_assign_slices(&mut s, &b);
+            for i in 0..n {
+                assert_eq!(s[i], a[i] + b[i]);
+            }
+
+            let mut s = a.clone();
+            sub_assign_slices(&mut s, &b);
+            for i in 0..n {
+                assert_eq!(s[i], a[i] - b[i]);
+            }
+
+            let mut s = a.clone();
+            scale_slice(&mut s, &c);
+            for i in 0..n {
+                assert_eq!(s[i], a[i] * c);
+            }
+
+            let mut s = a.clone();
+            axpy(&mut s, &c, &b);
+            for i in 0..n {
+                assert_eq!(s[i], a[i] + b[i] * c);
+            }
+        }
+    }
+
+    #[test]
+    fn test_group_slices() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let a: Vec<_> = (0..5).map(|_| Group::random(&mut rng)).collect();
+        let b: Vec<_> = (0..5).map(|_| Group::random(&mut rng)).collect();
+        let c = PrimeField::random(&mut rng);
+
+        let mut s = a.clone();
+        add_assign_slices(&mut s, &b);
+        scale_slice(&mut s, &c);
+        axpy(&mut s, &c, &b);
+        for i in 0..a.len() {
+            assert_eq!(s[i], (a[i] + b[i]) * c + b[i] * c);
+        }
+    }
+
+    #[test]
+    fn test_empty_slices() {
+        let c = PrimeField::ONE;
+        let mut a: Vec<PrimeField> = vec![];
+
+        add_assign_slices(&mut a, &[]);
+        sub_assign_slices(&mut a, &[]);
+        scale_slice(&mut a, &c);
+        axpy(&mut a, &c, &[]);
+
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let result = panic::catch_unwind(|| {
+            let mut a = vec![PrimeField::ONE; 2];
+            add_assign_slices(&mut a, &[PrimeField::ONE; 3]);
+        });
+        assert!(result.is_err());
+
+        let result = panic::catch_unwind(|| {
+            let mut a = vec![PrimeField::ONE; 3];
+            sub_assign_slices(&mut a, &[PrimeField::ONE; 2]);
+        });
+        assert!(result.is_err());
+
+        let result = panic::catch_unwind(|| {
+            let mut a = vec![PrimeField::ONE; 2];
+            axpy(&mut a, &PrimeField::ONE, &[PrimeField::ONE; 3]);
+        });
+        assert!(result.is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    cmp::{max, min},
+    cmp::max,
     iter::Sum,
     ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
@@ -7,9 +7,18 @@ use std::{
 use group::ff::PrimeField;
 use rand_core::RngCore;
 use subtle::{Choice, CtOption};
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
-use crate::poly::powers;
+use crate::poly::{
+    fft,
+    lagrange::lagrange,
+    ops::{add_assign_slices, axpy, scale_slice, sub_assign_slices},
+    powers, Point,
+};
+
+/// The number of points from which [`Polynomial::eval_many`] evaluates
+/// the polynomial using a subproduct tree.
+pub const EVAL_MANY_THRESHOLD: usize = 256;
 
 /// Univariate polynomial over a non-binary prime field.
 ///
@@ -163,6 +172,171 @@ where
 
         r
     }
+
+    /// Evaluates the polynomial at the given points, returning the values
+    /// in the same order as the points.
+    ///
+    /// For at least [`EVAL_MANY_THRESHOLD`] points, the polynomial is reduced
+    /// modulo a subproduct tree of the points using FFT-based arithmetic,
+    /// which takes `O(n log^2 n)` field operations instead of `O(n^2)`.
+    /// Fields without sufficiently large two-adicity, such as the scalar field
+    /// of P-384, always fall back to evaluating one point at a time.
+    ///
+    /// This method is not constant time.
+    pub fn eval_many(&self, points: &[F]) -> Vec<F> {
+        self.eval_many_with_threshold(points, EVAL_MANY_THRESHOLD)
+    }
+
+    /// Evaluates the polynomial at the given points, falling back to
+    /// evaluating one point at a time if there are fewer points than
+    /// the given threshold.
+    ///
+    /// See [`Self::eval_many`] for details.
+    pub fn eval_many_with_threshold(&self, points: &[F], threshold: usize) -> Vec<F> {
+        let len = max(self.a.len(), points.len() + 1);
+        if points.is_empty() || points.len() < threshold || fft::fft_size::<F>(2 * len).is_none() {
+            return points.iter().map(|x| self.eval(x)).collect();
+        }
+
+        // Build the subproduct tree bottom-up, where level 0 holds
+        // the polynomials (x - x_i) and every node on the next level
+        // holds the product of two adjacent nodes.
+        let mut tree = vec![points.iter().map(|x| vec![-*x, F::ONE]).collect::<Vec<_>>()];
+        while tree[tree.len() - 1].len() > 1 {
+            let level = tree[tree.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [lhs, rhs] if lhs.len() == rhs.len() => fft::mul_monic(lhs, rhs),
+                    [lhs, rhs] => fft::mul(lhs, rhs),
+                    [node] => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            tree.push(level);
+        }
+
+        // Descend the tree, reducing the remainders modulo the children,
+        // until the remainders modulo (x - x_i), i.e., the values, remain.
+        let root = &tree[tree.len() - 1][0];
+        let mut rems = vec![fft::rem(&self.a, root)];
+        for level in tree.iter().rev().skip(1) {
+            rems = level
+                .chunks(2)
+                .zip(rems.iter())
+                .flat_map(|(nodes, r)| nodes.iter().map(move |node| fft::rem(r, node)))
+                .collect();
+        }
+
+        rems.into_iter().map(|r| r[0]).collect()
+    }
+
+    /// Returns the Lagrange interpolation polynomial for the given points
+    /// `(x_i, y_i)`, or `None` if there are no points or the x-coordinates
+    /// are not unique.
+    ///
+    /// The degree of the polynomial is one less than the number of points.
+    ///
+    /// This method is not constant time.
+    pub fn interpolate(points: &[(F, F)]) -> Option<Self>
+    where
+        F: Zeroize,
+    {
+        if points.is_empty() {
+            return None;
+        }
+        for (i, (xi, _)) in points.iter().enumerate() {
+            if points[i + 1..].iter().any(|(xj, _)| xi == xj) {
+                return None;
+            }
+        }
+
+        let points: Zeroizing<Vec<_>> =
+            Zeroizing::new(points.iter().map(|&(x, y)| Point::new(x, y)).collect());
+        let points: Vec<_> = points.iter().collect();
+
+        Some(lagrange(&points))
+    }
+
+    /// Evaluates the Lagrange interpolation polynomial for the given points
+    /// `(x_i, y_i)` at the given value without computing its coefficients,
+    /// or returns `None` if there are no points or the x-coordinates are not
+    /// unique.
+    ///
+    /// The interpolation polynomial evaluated at `x` is:
+    /// ```text
+    /// L(x) = \sum_{i=0}^n y_i * \prod_{j=0,j≠i}^n (x - x_j) / (x_i - x_j)
+    /// ```
+    ///
+    /// This method is not constant time.
+    pub fn interpolate_at(points: &[(F, F)], x: &F) -> Option<F> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let mut r = F::ZERO;
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            let mut nom = F::ONE;
+            let mut denom = F::ONE;
+            for (j, (xj, _)) in points.iter().enumerate() {
+                if j == i {
+                    continue;
+                }
+                nom *= *x - xj; // (x - x_j)
+                denom *= *xi - xj; // (x_i - x_j)
+            }
+
+            // The denominator is zero only if the x-coordinates are not unique.
+            let denom_inv: Option<F> = denom.invert().into();
+            r += *yi * nom * denom_inv?;
+        }
+
+        Some(r)
+    }
+
+    /// Divides the polynomial by the given divisor, returning the quotient
+    /// and the remainder, or `None` if the divisor is zero.
+    ///
+    /// Unlike other operations, the quotient and the remainder are trimmed
+    /// of zero leading coefficients, so that their sizes reflect their actual
+    /// degrees. In particular, the remainder is the constant zero polynomial
+    /// if and only if the divisor divides the polynomial.
+    ///
+    /// This method is not constant time.
+    pub fn div_rem(&self, divisor: &Polynomial<F>) -> Option<(Polynomial<F>, Polynomial<F>)> {
+        let b = trimmed(&divisor.a);
+        let (lead, _) = b.split_last()?;
+        let lead_inv: Option<F> = lead.invert().into();
+        let lead_inv = lead_inv?;
+
+        let m = b.len() - 1; // The degree of the divisor.
+        let mut r = trimmed(&self.a).to_vec();
+        if r.len() <= m {
+            return Some((Self::zero(0), Self::with_coefficients(r)));
+        }
+
+        // Eliminate the leading coefficients of the remainder one by one.
+        let mut q = vec![F::ZERO; r.len() - m];
+        for i in (m..r.len()).rev() {
+            let c = r[i] * lead_inv;
+            axpy(&mut r[i - m..=i], &-c, b);
+            q[i - m] = c;
+        }
+        r.truncate(m);
+
+        let q = trimmed(&q).to_vec();
+        let r = trimmed(&r).to_vec();
+
+        Some((Self::with_coefficients(q), Self::with_coefficients(r)))
+    }
+}
+
+/// Returns the given coefficients without zero leading coefficients.
+fn trimmed<F: PrimeField>(a: &[F]) -> &[F] {
+    let len = a
+        .iter()
+        .rposition(|ai| !bool::from(ai.is_zero()))
+        .map_or(0, |i| i + 1);
+    &a[..len]
 }
 
 impl<F> Default for Polynomial<F>
@@ -222,15 +396,11 @@ where
 
     fn add(self, rhs: &Polynomial<F>) -> Polynomial<F> {
         let max_len = max(self.a.len(), rhs.a.len());
-        let min_len = min(self.a.len(), rhs.a.len());
         let mut a = Vec::with_capacity(max_len);
+        a.extend_from_slice(&self.a);
+        a.resize(max_len, F::ZERO);
 
-        for i in 0..min_len {
-            a.push(self.a[i] + rhs.a[i]);
-        }
-
-        a.extend(self.a[min_len..].iter());
-        a.extend(rhs.a[min_len..].iter());
+        add_assign_slices(&mut a[..rhs.a.len()], &rhs.a);
 
         Polynomial::with_coefficients(a)
     }
@@ -258,13 +428,10 @@ where
             self.a = a;
         }
 
-        let min_len = min(self.a.len(), rhs.a.len());
-
-        for i in 0..min_len {
-            self.a[i] += rhs.a[i];
-        }
+        let max_len = max(self.a.len(), rhs.a.len());
+        self.a.resize(max_len, F::ZERO);
 
-        self.a.extend(rhs.a[min_len..].iter());
+        add_assign_slices(&mut self.a[..rhs.a.len()], &rhs.a);
     }
 }
 
@@ -312,15 +479,11 @@ where
 
     fn sub(self, rhs: &Polynomial<F>) -> Polynomial<F> {
         let max_len = max(self.a.len(), rhs.a.len());
-        let min_len = min(self.a.len(), rhs.a.len());
         let mut a = Vec::with_capacity(max_len);
+        a.extend_from_slice(&self.a);
+        a.resize(max_len, F::ZERO);
 
-        for i in 0..min_len {
-            a.push(self.a[i] - rhs.a[i]);
-        }
-
-        a.extend(self.a[min_len..].iter());
-        a.extend(rhs.a[min_len..].iter().map(|ai| ai.neg()));
+        sub_assign_slices(&mut a[..rhs.a.len()], &rhs.a);
 
         Polynomial::with_coefficients(a)
     }
@@ -348,13 +511,10 @@ where
             self.a = a;
         }
 
-        let min_len = min(self.a.len(), rhs.a.len());
-
-        for i in 0..min_len {
-            self.a[i] -= rhs.a[i];
-        }
+        let max_len = max(self.a.len(), rhs.a.len());
+        self.a.resize(max_len, F::ZERO);
 
-        self.a.extend(rhs.a[min_len..].iter().map(|ai| ai.neg()));
+        sub_assign_slices(&mut self.a[..rhs.a.len()], &rhs.a);
     }
 }
 
@@ -401,16 +561,9 @@ where
     type Output = Polynomial<F>;
 
     fn mul(self, rhs: &Polynomial<F>) -> Polynomial<F> {
-        let mut a = Vec::with_capacity(self.a.len() + rhs.a.len() - 1);
-        for i in 0..self.a.len() {
-            for j in 0..rhs.a.len() {
-                let aij = self.a[i] * rhs.a[j];
-                if i + j < a.len() {
-                    a[i + j] += aij;
-                } else {
-                    a.push(aij);
-                }
-            }
+        let mut a = vec![F::ZERO; self.a.len() + rhs.a.len() - 1];
+        for (i, ai) in self.a.iter().enumerate() {
+            axpy(&mut a[i..i + rhs.a.len()], ai, &rhs.a);
         }
         Polynomial::with_coefficients(a)
     }
@@ -431,16 +584,9 @@ where
     F: PrimeField,
 {
     fn mul_assign(&mut self, rhs: &Polynomial<F>) {
-        let mut a = Vec::with_capacity(self.a.len() + rhs.a.len() - 1);
-        for i in 0..self.a.len() {
-            for j in 0..rhs.a.len() {
-                let aij = self.a[i] * rhs.a[j];
-                if i + j < a.len() {
-                    a[i + j] += aij;
-                } else {
-                    a.push(aij);
-                }
-            }
+        let mut a = vec![F::ZERO; self.a.len() + rhs.a.len() - 1];
+        for (i, ai) in self.a.iter().enumerate() {
+            axpy(&mut a[i..i + rhs.a.len()], ai, &rhs.a);
         }
         self.a = a;
     }
@@ -491,11 +637,8 @@ where
     type Output = Polynomial<F>;
 
     fn mul(self, scalar: &F) -> Polynomial<F> {
-        let mut a = Vec::with_capacity(self.a.len());
-
-        for i in 0..self.a.len() {
-            a.push(self.a[i] * scalar);
-        }
+        let mut a = self.a.clone();
+        scale_slice(&mut a, scalar);
 
         Polynomial::with_coefficients(a)
     }
@@ -516,9 +659,7 @@ where
     F: PrimeField,
 {
     fn mul_assign(&mut self, scalar: &F) {
-        for i in 0..self.a.len() {
-            self.a[i] *= scalar
-        }
+        scale_slice(&mut self.a, scalar);
     }
 }
 
@@ -557,6 +698,11 @@ where
 
 #[cfg(test)]
 mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use group::ff::Field;
     use rand::{rngs::StdRng, SeedableRng};
 
     type PrimeField = p384::Scalar;
@@ -670,6 +816,118 @@ mod tests {
         assert!(bp == restored);
     }
 
+    #[test]
+    fn test_interpolate() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let p = Polynomial::random(3, &mut rng);
+        let points: Vec<_> = (1..=6).map(|k| (scalar(k), p.eval(&scalar(k)))).collect();
+
+        // Exactly enough points.
+        let q = Polynomial::interpolate(&points[..4]).expect("interpolation should succeed");
+        assert!(q == p);
+
+        // More points than needed.
+        let q = Polynomial::interpolate(&points).expect("interpolation should succeed");
+        assert_eq!(q.a[..4], p.a[..]);
+        assert!(q.a[4..].iter().all(|ai| *ai == PrimeField::ZERO));
+
+        // Not enough points.
+        let q = Polynomial::interpolate(&points[..3]).expect("interpolation should succeed");
+        assert_ne!(q.eval(&scalar(0)), p.eval(&scalar(0)));
+
+        // Single point.
+        let q = Polynomial::interpolate(&points[..1]).expect("interpolation should succeed");
+        assert_eq!(q.a, vec![points[0].1]);
+
+        // Duplicate x-coordinates or no points.
+        let mut duplicates = points[..4].to_vec();
+        duplicates[3].0 = duplicates[1].0;
+        assert!(Polynomial::interpolate(&duplicates).is_none());
+        assert!(Polynomial::interpolate(&[]).is_none());
+    }
+
+    #[test]
+    fn test_interpolate_at() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let p = Polynomial::random(3, &mut rng);
+        let points: Vec<_> = (1..=5).map(|k| (scalar(k), p.eval(&scalar(k)))).collect();
+
+        for x in scalars(&[0, 1, 3, 7, -2]) {
+            for n in 4..=5 {
+                let y = Polynomial::interpolate_at(&points[..n], &x)
+                    .expect("interpolation should succeed");
+                assert_eq!(y, p.eval(&x));
+            }
+        }
+
+        // Matches the interpolation polynomial.
+        let q = Polynomial::interpolate(&points[..3]).expect("interpolation should succeed");
+        let y = Polynomial::interpolate_at(&points[..3], &scalar(0))
+            .expect("interpolation should succeed");
+        assert_eq!(y, q.eval(&scalar(0)));
+
+        // Duplicate x-coordinates or no points.
+        let mut duplicates = points.clone();
+        duplicates[4].0 = duplicates[0].0;
+        assert!(Polynomial::interpolate_at(&duplicates, &scalar(0)).is_none());
+        assert!(Polynomial::interpolate_at(&[], &scalar(0)).is_none());
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // Random polynomials.
+        for (deg_a, deg_b) in [(7, 3), (3, 3), (5, 0), (2, 4)] {
+            let a = Polynomial::random(deg_a, &mut rng);
+            let b = Polynomial::random(deg_b, &mut rng);
+            let (q, r) = a.div_rem(&b).unwrap();
+
+            assert_eq!(
+                q.size(),
+                (deg_a as usize + 1).saturating_sub(deg_b as usize).max(1)
+            );
+            assert!(r.size() <= (deg_b as usize).max(1));
+            let x = PrimeField::random(&mut rng);
+            assert_eq!(q.eval(&x) * b.eval(&x) + r.eval(&x), a.eval(&x));
+        }
+
+        // Exact division.
+        let a = Polynomial::with_coefficients(scalars(&[-6, 11, -6, 1])); // (x-1)(x-2)(x-3)
+        let b = Polynomial::with_coefficients(scalars(&[2, -3, 1])); // (x-1)(x-2)
+        let (q, r) = a.div_rem(&b).unwrap();
+        assert_eq!(q.a, scalars(&[-3, 1]));
+        assert_eq!(r.a, scalars(&[0]));
+
+        // Zero leading coefficients are ignored and trimmed.
+        let a = Polynomial::with_coefficients(scalars(&[-6, 11, -6, 1, 0, 0]));
+        let b = Polynomial::with_coefficients(scalars(&[2, -3, 1, 0]));
+        let (q, r) = a.div_rem(&b).unwrap();
+        assert_eq!(q.a, scalars(&[-3, 1]));
+        assert_eq!(r.a, scalars(&[0]));
+
+        // Non-zero remainder.
+        let a = Polynomial::with_coefficients(scalars(&[1, 0, 1])); // x^2 + 1
+        let b = Polynomial::with_coefficients(scalars(&[-1, 1])); // x - 1
+        let (q, r) = a.div_rem(&b).unwrap();
+        assert_eq!(q.a, scalars(&[1, 1]));
+        assert_eq!(r.a, scalars(&[2]));
+
+        // Dividend of smaller degree.
+        let (q, r) = b.div_rem(&a).unwrap();
+        assert_eq!(q.a, scalars(&[0]));
+        assert_eq!(r.a, b.a);
+
+        // Zero dividend.
+        let (q, r) = Polynomial::zero(3).div_rem(&b).unwrap();
+        assert_eq!(q.a, scalars(&[0]));
+        assert_eq!(r.a, scalars(&[0]));
+
+        // Zero divisor.
+        assert!(a.div_rem(&Polynomial::zero(0)).is_none());
+        assert!(a.div_rem(&Polynomial::zero(2)).is_none());
+    }
+
     #[test]
     pub fn test_eval() {
         let f = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
@@ -869,4 +1127,102 @@ mod tests {
             assert_eq!(v.a, h.a);
         }
     }
+
+    #[test]
+    fn test_eval_many() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // P-384 always evaluates one point at a time.
+        let p = Polynomial::random(10, &mut rng);
+        let xs: Vec<_> = (0..20).map(|_| PrimeField::random(&mut rng)).collect();
+        let expected: Vec<_> = xs.iter().map(|x| p.eval(x)).collect();
+        assert_eq!(p.eval_many(&xs), expected);
+        assert_eq!(p.eval_many_with_threshold(&xs, 0), expected);
+        assert!(p.eval_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_eval_many_tree() {
+        type Polynomial = super::Polynomial<bls12_381::Scalar>;
+
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // BLS12-381 uses the subproduct tree above the threshold.
+        for (deg, n) in [
+            (0, 1),
+            (0, 5),
+            (3, 1),
+            (3, 2),
+            (10, 7),
+            (10, 33),
+            (100, 65),
+            (50, 150),
+            (255, 300),
+        ] {
+            let p = Polynomial::random(deg, &mut rng);
+            let xs: Vec<_> = (0..n)
+                .map(|_| bls12_381::Scalar::random(&mut rng))
+                .collect();
+            let expected: Vec<_> = xs.iter().map(|x| p.eval(x)).collect();
+
+            assert_eq!(p.eval_many(&xs), expected);
+            assert_eq!(p.eval_many_with_threshold(&xs, 0), expected);
+            assert_eq!(p.eval_many_with_threshold(&xs, usize::MAX), expected);
+        }
+
+        // Repeated points.
+        let p = Polynomial::random(5, &mut rng);
+        let x = bls12_381::Scalar::random(&mut rng);
+        let xs = vec![x, bls12_381::Scalar::ONE, x, x];
+        let expected: Vec<_> = xs.iter().map(|x| p.eval(x)).collect();
+        assert_eq!(p.eval_many_with_threshold(&xs, 0), expected);
+
+        let p = Polynomial::random(5, &mut rng);
+        assert!(p.eval_many_with_threshold(&[], 0).is_empty());
+    }
+
+    fn bench_eval_many(b: &mut Bencher, n: usize, threshold: usize) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let a = (0..n)
+            .map(|_| bls12_381::Scalar::random(&mut rng))
+            .collect();
+        let p = super::Polynomial::with_coefficients(a);
+        let xs: Vec<_> = (0..n)
+            .map(|_| bls12_381::Scalar::random(&mut rng))
+            .collect();
+
+        b.iter(|| {
+            let _v = p.eval_many_with_threshold(&xs, threshold);
+        });
+    }
+
+    #[bench]
+    fn bench_eval_many_tree_128(b: &mut Bencher) {
+        bench_eval_many(b, 128, 0)
+    }
+
+    #[bench]
+    fn bench_eval_many_tree_256(b: &mut Bencher) {
+        bench_eval_many(b, 256, 0)
+    }
+
+    #[bench]
+    fn bench_eval_many_tree_512(b: &mut Bencher) {
+        bench_eval_many(b, 512, 0)
+    }
+
+    #[bench]
+    fn bench_eval_many_naive_128(b: &mut Bencher) {
+        bench_eval_many(b, 128, usize::MAX)
+    }
+
+    #[bench]
+    fn bench_eval_many_naive_256(b: &mut Bencher) {
+        bench_eval_many(b, 256, usize::MAX)
+    }
+
+    #[bench]
+    fn bench_eval_many_naive_512(b: &mut Bencher) {
+        bench_eval_many(b, 512, usize::MAX)
+    }
 }
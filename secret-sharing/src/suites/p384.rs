@@ -1,9 +1,17 @@
+use std::sync::OnceLock;
+
 use anyhow::Result;
 
 use p384::{
-    elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest as _},
-    NistP384, ProjectivePoint, Scalar,
+    elliptic_curve::{
+        hash2curve::{ExpandMsgXmd, GroupDigest as _},
+        sec1::{FromEncodedPoint, ToEncodedPoint, UncompressedPoint},
+    },
+    AffinePoint, EncodedPoint, NistP384, ProjectivePoint, Scalar,
 };
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+use crate::vss::{GeneratorMul, GeneratorTable, Sec1Encoding, UncompressedEncoding};
 
 use super::{FieldDigest, GroupDigest};
 
@@ -34,6 +42,39 @@ impl FieldDigest for Sha3_384 {
     }
 }
 
+impl Sec1Encoding for ProjectivePoint {}
+
+impl GeneratorMul for ProjectivePoint {
+    fn mul_generator(s: &Scalar) -> Self {
+        // The table is built once, on first use, and shared by all callers.
+        static TABLE: OnceLock<GeneratorTable<ProjectivePoint>> = OnceLock::new();
+        TABLE.get_or_init(GeneratorTable::new).mul(s)
+    }
+}
+
+impl UncompressedEncoding for ProjectivePoint {
+    type Uncompressed = UncompressedPoint<NistP384>;
+
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        EncodedPoint::from_bytes(bytes)
+            .map(|point| CtOption::new(point, Choice::from(1)))
+            .unwrap_or_else(|_| {
+                // The identity is encoded as all zeros to keep the width fixed.
+                let is_identity = bytes.as_slice().ct_eq(&Self::Uncompressed::default());
+                CtOption::new(EncodedPoint::identity(), is_identity)
+            })
+            .and_then(|point| AffinePoint::from_encoded_point(&point))
+            .map(ProjectivePoint::from)
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        let encoded = self.to_encoded_point(false);
+        let mut result = Self::Uncompressed::default();
+        result[..encoded.len()].copy_from_slice(encoded.as_bytes());
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate test;
@@ -42,7 +83,47 @@ mod tests {
 
     use rand::{rngs::StdRng, RngCore, SeedableRng};
 
-    use super::{FieldDigest, GroupDigest, Sha3_384};
+    use group::{Group, GroupEncoding};
+
+    use crate::vss::UncompressedEncoding;
+
+    use super::{FieldDigest, GroupDigest, ProjectivePoint, Sha3_384};
+
+    #[test]
+    fn test_uncompressed_encoding() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for p in [
+            ProjectivePoint::identity(),
+            ProjectivePoint::generator(),
+            ProjectivePoint::random(&mut rng),
+        ] {
+            let bytes = p.to_uncompressed();
+            assert_eq!(bytes.len(), 97);
+
+            let restored: Option<ProjectivePoint> =
+                ProjectivePoint::from_uncompressed(&bytes).into();
+            assert_eq!(restored, Some(p));
+        }
+
+        // Identity is encoded as all zeros.
+        let bytes = ProjectivePoint::identity().to_uncompressed();
+        assert!(bytes.iter().all(|&b| b == 0));
+
+        // Uncompressed tag with a point not on the curve.
+        let mut bytes = ProjectivePoint::generator().to_uncompressed();
+        bytes[96] ^= 1;
+        assert!(bool::from(
+            ProjectivePoint::from_uncompressed(&bytes).is_none()
+        ));
+
+        // Compressed tag.
+        let mut bytes = ProjectivePoint::generator().to_uncompressed();
+        bytes[0] = ProjectivePoint::generator().to_bytes()[0];
+        assert!(bool::from(
+            ProjectivePoint::from_uncompressed(&bytes).is_none()
+        ));
+    }
 
     #[bench]
     fn bench_hash_to_field_p384_sha3_384(b: &mut Bencher) {
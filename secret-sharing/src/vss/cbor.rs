@@ -0,0 +1,302 @@
+//! CBOR encoding of verification matrices.
+//!
+//! Verification matrices are encoded as a CBOR byte string holding their
+//! canonical byte representation, which is how the Go consensus layer
+//! encodes and decodes them.
+
+use cbor::{reader::DecoderError, Value};
+use group::{Group, GroupEncoding};
+
+use super::{DecodeError, VerificationMatrix};
+
+/// The maximum size of the byte representation of a verification matrix
+/// accepted when decoding through [`cbor::Decode`].
+///
+/// The limit fits matrices of up to 256x256 compressed elements of all
+/// supported groups. Larger matrices must be decoded using
+/// [`VerificationMatrix::from_cbor`] with an explicit limit.
+pub const MAX_CBOR_SIZE: usize = 1 << 24;
+
+impl<G> VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Returns the CBOR encoding of the verification matrix, i.e.,
+    /// its byte representation as produced by [`Self::to_bytes`],
+    /// wrapped in a CBOR byte string.
+    pub fn to_cbor(&self) -> Vec<u8> {
+        cbor::to_vec(Value::ByteString(self.to_bytes()))
+    }
+
+    /// Attempts to create a verification matrix from its CBOR encoding,
+    /// rejecting byte representations longer than the given maximum size.
+    ///
+    /// The input must consist of exactly one canonically encoded byte string
+    /// followed by nothing else.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn from_cbor(bytes: &[u8], max_size: usize) -> Result<Self, DecodeError> {
+        let value = cbor::reader::read(bytes).map_err(|err| match err {
+            DecoderError::IncompleteCborData => DecodeError::TooShort,
+            DecoderError::ExtraneousData => DecodeError::TrailingBytes {
+                count: bytes.len() - Self::cbor_item_size(bytes),
+            },
+            _ => DecodeError::InvalidCbor,
+        })?;
+
+        Self::from_cbor_value(value, max_size)
+    }
+
+    /// Attempts to create a verification matrix from a decoded CBOR value,
+    /// rejecting byte representations longer than the given maximum size.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn from_cbor_value(value: Value, max_size: usize) -> Result<Self, DecodeError> {
+        let bytes = match value {
+            Value::ByteString(bytes) => bytes,
+            value => return Err(DecodeError::UnexpectedMajorType(value.type_label())),
+        };
+
+        if bytes.len() > max_size {
+            return Err(DecodeError::TooLarge {
+                max: max_size,
+                got: bytes.len() as u64,
+            });
+        }
+
+        Self::try_from_bytes(&bytes)
+    }
+
+    /// Returns the size of the leading data item of the given CBOR encoding,
+    /// which must be well-formed.
+    fn cbor_item_size(bytes: &[u8]) -> usize {
+        cbor::reader::read_nested_non_strict(bytes, None)
+            .map(|value| cbor::to_vec(value).len())
+            .unwrap_or(bytes.len())
+    }
+}
+
+impl<G> cbor::Encode for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn into_cbor_value(self) -> Value {
+        Value::ByteString(self.to_bytes())
+    }
+}
+
+impl<G> cbor::Decode for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn try_from_cbor_value(value: Value) -> Result<Self, cbor::DecodeError> {
+        Self::from_cbor_value(value, MAX_CBOR_SIZE).map_err(|err| match err {
+            DecodeError::UnexpectedMajorType(_) => cbor::DecodeError::UnexpectedType,
+            _ => cbor::DecodeError::ParsingFailed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cbor::Value;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        poly,
+        vss::{self, DecodeError},
+    };
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    /// The CBOR encoding of the verification matrix of the bivariate
+    /// polynomial `B(x,y) = 1 + 2y + 3x + 4xy`.
+    ///
+    /// The byte representation of the matrix is produced by this crate,
+    /// while the CBOR framing around it is the one the Go encoder emits
+    /// for a byte slice. The Go test `TestVerificationMatrixCBOR` in
+    /// `go/keymanager/churp/verification_matrix_test.go` asserts the same
+    /// encoding and logs it when run with `-v`.
+    const FIXTURE: &str = concat!(
+        "58c6010103aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741",
+        "e082542a385502f25dbf55296c3a545e3872760ab70208d999057ba3d2d96926",
+        "0045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96",
+        "a9c75295df6103077a41d4606ffa1464793c7e5fdc7d98cb9d3910202dcd06be",
+        "a4f240d3566da6b408bbae5026580d02d7e5c70500c83103138251cd52ac9298",
+        "c1c8aad977321deb97e709bd0b4ca0aca55dc8ad51dcfc9d1589a1597e3a5120",
+        "e1efd631c63e1835",
+    );
+
+    fn fixture() -> VerificationMatrix {
+        let b = vec![
+            vec![PrimeField::from_u64(1), PrimeField::from_u64(2)],
+            vec![PrimeField::from_u64(3), PrimeField::from_u64(4)],
+        ];
+        let bp = BivariatePolynomial::with_coefficients(b);
+        VerificationMatrix::from(&bp)
+    }
+
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("hex should be valid"))
+            .collect()
+    }
+
+    #[test]
+    fn test_fixture() {
+        let vm = fixture();
+        let cbor = vm.to_cbor();
+        assert_eq!(cbor, decode_hex(FIXTURE));
+
+        assert_eq!(cbor[..4], [0x58, 0xC6, 1, 1]);
+
+        let restored = VerificationMatrix::from_cbor(&cbor, 0xC6);
+        assert_eq!(restored, Ok(vm));
+    }
+
+    #[test]
+    fn test_to_cbor() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // Lengths encoded in one, two and four bytes.
+        let test_cases = vec![
+            ((0, 0), vec![0x58, 51]),
+            ((4, 4), vec![0x59, 0x04, 0xCB]),
+            ((300, 0), vec![0x59, 0x39, 0xA4]),
+            ((1400, 0), vec![0x5A, 0x00, 0x01, 0x0C, 0x30]),
+        ];
+
+        for ((deg_x, deg_y), header) in test_cases {
+            let b = vec![vec![PrimeField::ONE; deg_y + 1]; deg_x + 1];
+            let vm = VerificationMatrix::from(&BivariatePolynomial::with_coefficients(b));
+            let bytes = vm.to_bytes();
+            let cbor = vm.to_cbor();
+
+            assert_eq!(cbor[..header.len()], header[..]);
+            assert_eq!(cbor[header.len()..], bytes[..]);
+
+            let restored = VerificationMatrix::from_cbor(&cbor, bytes.len());
+            assert_eq!(restored.as_ref(), Ok(&vm));
+        }
+
+        let vm = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        let cbor = vm.to_cbor();
+        let restored = VerificationMatrix::from_cbor(&cbor, usize::MAX);
+        assert_eq!(restored, Ok(vm));
+    }
+
+    #[test]
+    fn test_from_cbor() {
+        let vm = fixture();
+        let cbor = vm.to_cbor();
+        let max = cbor.len() - 2;
+
+        // Empty input.
+        let res = VerificationMatrix::from_cbor(&[], max);
+        assert_eq!(res, Err(DecodeError::TooShort));
+
+        // Trailing garbage.
+        let mut trailing = cbor.clone();
+        trailing.extend_from_slice(&[0x00, 0x01]);
+        let res = VerificationMatrix::from_cbor(&trailing, max);
+        assert_eq!(res, Err(DecodeError::TrailingBytes { count: 2 }));
+
+        // Truncated payload or length.
+        let res = VerificationMatrix::from_cbor(&cbor[..cbor.len() - 1], max);
+        assert_eq!(res, Err(DecodeError::TooShort));
+        let res = VerificationMatrix::from_cbor(&cbor[..1], max);
+        assert_eq!(res, Err(DecodeError::TooShort));
+        let huge = [0x5B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let res = VerificationMatrix::from_cbor(&huge, max);
+        assert_eq!(res, Err(DecodeError::TooShort));
+
+        // Wrong major types: unsigned integer, text string, array, map.
+        let test_cases = vec![
+            (cbor::to_vec(max as u64), 0),
+            (cbor::to_vec("matrix".to_string()), 3),
+            (cbor::to_vec(vec![1u64, 2]), 4),
+            (cbor::to_vec(Value::Map(vec![])), 5),
+        ];
+        for (wrong, major) in test_cases {
+            let res = VerificationMatrix::from_cbor(&wrong, max);
+            assert_eq!(res, Err(DecodeError::UnexpectedMajorType(major)));
+        }
+
+        // Oversized payload.
+        let res = VerificationMatrix::from_cbor(&cbor, max - 1);
+        assert_eq!(
+            res,
+            Err(DecodeError::TooLarge {
+                max: max - 1,
+                got: max as u64
+            })
+        );
+
+        // Non-minimal length encoding.
+        let mut non_minimal = vec![0x59, 0x00, 0xC6];
+        non_minimal.extend_from_slice(&cbor[2..]);
+        let res = VerificationMatrix::from_cbor(&non_minimal, max);
+        assert_eq!(res, Err(DecodeError::InvalidCbor));
+
+        // Indefinite-length byte string.
+        let mut indefinite = vec![0x5F];
+        indefinite.extend_from_slice(&cbor);
+        indefinite.push(0xFF);
+        let res = VerificationMatrix::from_cbor(&indefinite, max);
+        assert_eq!(res, Err(DecodeError::InvalidCbor));
+
+        // Invalid matrix.
+        let mut invalid = cbor.clone();
+        invalid[2] = 2;
+        let res = VerificationMatrix::from_cbor(&invalid, max);
+        assert_eq!(
+            res,
+            Err(DecodeError::LengthMismatch {
+                expected: 2 + 6 * 49,
+                got: max
+            })
+        );
+    }
+
+    #[test]
+    fn test_cbor_traits() {
+        let vm = fixture();
+
+        let encoded = cbor::to_vec(vm.clone());
+        assert_eq!(encoded, decode_hex(FIXTURE));
+
+        let decoded: VerificationMatrix =
+            cbor::from_slice(&encoded).expect("decoding should succeed");
+        assert_eq!(decoded, vm);
+
+        // Wrong major type.
+        let encoded = cbor::to_vec(vm.to_bytes().len() as u64);
+        let res: Result<VerificationMatrix, _> = cbor::from_slice(&encoded);
+        assert!(matches!(res, Err(cbor::DecodeError::UnexpectedType)));
+
+        // Invalid matrix.
+        let encoded = cbor::to_vec(Value::ByteString(vec![0u8; 3]));
+        let res: Result<VerificationMatrix, _> = cbor::from_slice(&encoded);
+        assert!(matches!(res, Err(cbor::DecodeError::ParsingFailed)));
+
+        // Oversized payload, rejected before its contents are parsed.
+        let value = Value::ByteString(vec![0u8; super::MAX_CBOR_SIZE + 1]);
+        let res: Result<VerificationMatrix, _> = cbor::from_value(value.clone());
+        assert!(matches!(res, Err(cbor::DecodeError::ParsingFailed)));
+
+        let res = VerificationMatrix::from_cbor_value(value, super::MAX_CBOR_SIZE);
+        assert_eq!(
+            res,
+            Err(DecodeError::TooLarge {
+                max: super::MAX_CBOR_SIZE,
+                got: super::MAX_CBOR_SIZE as u64 + 1
+            })
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use group::{Group, GroupEncoding};
+
+use super::GeneratorMul;
+
+/// Public commitment `s * G` to the secret `s = B(0,0)` shared by
+/// a bivariate polynomial, i.e., the element `M_{0,0}` of its verification
+/// matrix.
+///
+/// Since verification matrices are additively homomorphic, the commitment
+/// of the sum of matrices dealt in a distributed key generation is the joint
+/// public key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecretCommitment<G>(G)
+where
+    G: Group;
+
+impl<G> SecretCommitment<G>
+where
+    G: Group,
+{
+    /// Creates a commitment from the given group element.
+    pub fn new(element: G) -> Self {
+        Self(element)
+    }
+
+    /// Returns the committed group element `s * G`.
+    pub fn element(&self) -> &G {
+        &self.0
+    }
+
+    /// Consumes the commitment, returning the committed group element.
+    pub fn into_inner(self) -> G {
+        self.0
+    }
+
+    /// Verifies whether the given secret, e.g., one recovered from shares,
+    /// is the committed one, i.e., if it holds `secret * G == M_{0,0}`.
+    pub fn verify_recovered(&self, secret: &G::Scalar) -> bool {
+        let diff = G::mul_generator(secret) - self.0;
+        diff.is_identity().into()
+    }
+}
+
+impl<G> SecretCommitment<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Returns the byte representation of the commitment, which is
+    /// the compressed encoding of the group element.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes().as_ref().to_vec()
+    }
+
+    /// Attempts to create a commitment from its byte representation.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut repr: G::Repr = Default::default();
+        if bytes.len() != repr.as_ref().len() {
+            return None;
+        }
+        repr.as_mut().copy_from_slice(bytes);
+
+        Option::from(G::from_bytes(&repr)).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{poly, vss};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type SecretCommitment = vss::SecretCommitment<Group>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    #[test]
+    fn test_secret_commitment() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = PrimeField::random(&mut rng);
+        let bp = BivariatePolynomial::random_with_secret(2, 3, secret, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let sc = vm.secret_commitment();
+        assert_eq!(Some(sc.element()), vm.element(0, 0));
+        assert!(sc.verify_recovered(&secret));
+        assert!(!sc.verify_recovered(&(secret + PrimeField::ONE)));
+
+        let bytes = sc.to_bytes();
+        assert_eq!(SecretCommitment::from_bytes(&bytes), Some(sc));
+        assert!(SecretCommitment::from_bytes(&bytes[1..]).is_none());
+        assert!(SecretCommitment::from_bytes(&[]).is_none());
+    }
+
+    #[test]
+    fn test_joint_secret_commitment() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secrets: Vec<_> = (0..3).map(|_| PrimeField::random(&mut rng)).collect();
+        let vms: Vec<_> = secrets
+            .iter()
+            .map(|s| BivariatePolynomial::random_with_secret(2, 2, *s, &mut rng))
+            .map(|bp| VerificationMatrix::from(&bp))
+            .collect();
+
+        let joint: PrimeField = secrets.iter().sum();
+        let vm = vms.into_iter().reduce(|a, b| a + b).unwrap();
+        assert!(vm.secret_commitment().verify_recovered(&joint));
+        assert_eq!(
+            *vm.secret_commitment().element(),
+            Group::generator() * joint
+        );
+    }
+}
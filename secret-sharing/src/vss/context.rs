@@ -0,0 +1,91 @@
+use group::ff::PrimeField;
+
+use crate::poly::powers;
+
+/// Precomputed powers of a fixed point `(x, y)` for repeated verification
+/// of evaluations of bivariate polynomials at that point.
+///
+/// The context caches the products `x^i * y^j` for all `i <= deg_x` and
+/// `j <= deg_y`, so that verifying against any verification matrix of at most
+/// these degrees requires no field multiplications to prepare the scalars:
+/// ```text
+///     B(x,y) * G = \sum_{i,j} x^i * y^j * M_{i,j}
+/// ```
+#[derive(Debug, Clone)]
+pub struct VerificationContext<F: PrimeField> {
+    /// The fixed `x` value.
+    x: F,
+    /// The fixed `y` value.
+    y: F,
+    /// The maximum degree in the `x` variable.
+    deg_x: usize,
+    /// The maximum degree in the `y` variable.
+    deg_y: usize,
+    /// The products `x^i * y^j` in row-major order, where `i` ranges over
+    /// the rows and `j` over the columns.
+    xypows: Vec<F>,
+}
+
+impl<F> VerificationContext<F>
+where
+    F: PrimeField,
+{
+    /// Creates a new context for the given point, usable with verification
+    /// matrices of degrees up to `deg_x` and `deg_y`.
+    pub fn new(x: F, y: F, deg_x: usize, deg_y: usize) -> Self {
+        let xpows = powers(&x, deg_x); // [x^i]
+        let ypows = powers(&y, deg_y); // [y^j]
+        let mut xypows = Vec::with_capacity(xpows.len() * ypows.len());
+        for xpow in xpows {
+            xypows.extend(ypows.iter().map(|ypow| xpow * ypow)); // x^i * y^j
+        }
+
+        Self {
+            x,
+            y,
+            deg_x,
+            deg_y,
+            xypows,
+        }
+    }
+
+    /// Returns the fixed `x` value.
+    pub fn x(&self) -> &F {
+        &self.x
+    }
+
+    /// Returns the fixed `y` value.
+    pub fn y(&self) -> &F {
+        &self.y
+    }
+
+    /// Returns the maximum degrees (in `x` and `y`) supported by the context.
+    pub fn degrees(&self) -> (usize, usize) {
+        (self.deg_x, self.deg_y)
+    }
+
+    /// Returns the products `x^i * y^j` for the top-left `rows x cols`
+    /// sub-matrix in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimensions exceed the degrees of the context.
+    pub(crate) fn scalars(&self, rows: usize, cols: usize) -> Vec<F> {
+        assert!(
+            rows <= self.deg_x + 1 && cols <= self.deg_y + 1,
+            "dimensions should not exceed the degrees of the context"
+        );
+
+        let stride = self.deg_y + 1;
+        if cols == stride {
+            return self.xypows[..rows * cols].to_vec();
+        }
+
+        let mut scalars = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            scalars.extend_from_slice(&self.xypows[i * stride..i * stride + cols]);
+        }
+
+        scalars
+    }
+}
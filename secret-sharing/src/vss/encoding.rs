@@ -0,0 +1,56 @@
+use group::{cofactor::CofactorGroup, Group, GroupEncoding};
+use subtle::{Choice, CtOption};
+
+/// The encoding of group elements in the byte representation
+/// of a verification matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointEncoding {
+    /// The compressed encoding given by [`GroupEncoding`], which is smaller
+    /// but requires decompression when decoded.
+    Compressed,
+    /// The uncompressed encoding given by [`UncompressedEncoding`], which is
+    /// larger but cheaper to decode.
+    Uncompressed,
+}
+
+/// A trait for encoding group elements in uncompressed form.
+pub trait UncompressedEncoding: GroupEncoding {
+    /// The uncompressed encoding of a group element.
+    type Uncompressed: Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// Attempts to decode a group element from its uncompressed encoding.
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self>;
+
+    /// Returns the uncompressed encoding of the group element.
+    fn to_uncompressed(&self) -> Self::Uncompressed;
+}
+
+/// A marker trait for groups whose compressed encodings follow SEC1, i.e.,
+/// start with `0x00` for the identity and `0x02` or `0x03` otherwise.
+///
+/// Since no element encoding can then start with the tag of an extended
+/// header, a reader can tell an extended header apart from a legacy header
+/// of a 256x256 matrix without knowing the length of the input, as done
+/// by [`VerificationMatrix::read_from`](super::VerificationMatrix::read_from).
+pub trait Sec1Encoding: GroupEncoding {}
+
+/// A trait for checking that group elements lie in the prime-order subgroup.
+///
+/// Decoding of groups with a cofactor may accept elements with a small-order
+/// component, which must be rejected before they are used in verification.
+/// The trait is implemented for all groups implementing [`CofactorGroup`].
+/// For prime-order groups the check always succeeds and compiles away.
+pub trait SubgroupCheck: Group {
+    /// Returns a choice indicating whether the element is contained
+    /// in the prime-order subgroup, i.e., has no small-order component.
+    fn is_torsion_free(&self) -> Choice;
+}
+
+impl<G> SubgroupCheck for G
+where
+    G: CofactorGroup,
+{
+    fn is_torsion_free(&self) -> Choice {
+        CofactorGroup::is_torsion_free(self)
+    }
+}
@@ -0,0 +1,91 @@
+use std::io;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("verification matrix digest mismatch")]
+    DigestMismatch,
+    #[error("verification matrix dimension mismatch: {lhs:?} and {rhs:?}")]
+    DimensionMismatch {
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+    #[error("verification matrix dimensions too large")]
+    DimensionsTooLarge,
+    #[error("duplicate share")]
+    DuplicateShare,
+    #[error("verification matrix element already present")]
+    ElementAlreadyPresent,
+    #[error("verification matrix element out of bounds")]
+    ElementOutOfBounds,
+    #[error("empty verification matrix")]
+    EmptyMatrix,
+    #[error("invalid verification matrix degrees")]
+    InvalidDegrees,
+    #[error("invalid share")]
+    InvalidShare,
+    #[error("verification matrix elements missing")]
+    MissingElements,
+    #[error("ragged verification matrix")]
+    RaggedMatrix,
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    #[error("verification matrix degrees {got:?} exceed the bounds {max:?}")]
+    DegreesExceeded {
+        max: (usize, usize),
+        got: (usize, usize),
+    },
+    #[error("identity verification matrix element at ({row}, {col})")]
+    IdentityElement { row: usize, col: usize },
+    #[error("leading verification matrix column consists of identity elements")]
+    IdentityLeadingColumn,
+    #[error("leading verification matrix row consists of identity elements")]
+    IdentityLeadingRow,
+    #[error("invalid cbor")]
+    InvalidCbor,
+    #[error("invalid verification matrix element at ({row}, {col})")]
+    InvalidElement { row: usize, col: usize },
+    #[error("i/o error: {0}")]
+    Io(io::ErrorKind),
+    #[error("length mismatch: expected {expected} bytes, got {got}")]
+    LengthMismatch { expected: usize, got: usize },
+    #[error("non-canonical verification matrix element at ({row}, {col})")]
+    NonCanonicalElement { row: usize, col: usize },
+    #[error("verification matrix size overflows")]
+    SizeOverflow,
+    #[error("verification matrix element at ({row}, {col}) has a small-order component")]
+    SmallOrderElement { row: usize, col: usize },
+    #[error("too large: at most {max} bytes allowed, got {got}")]
+    TooLarge { max: usize, got: u64 },
+    #[error("too short")]
+    TooShort,
+    #[error("trailing bytes: {count}")]
+    TrailingBytes { count: usize },
+    #[error("unexpected point encoding")]
+    UnexpectedEncoding,
+    #[error("unexpected cbor major type {0}")]
+    UnexpectedMajorType(u8),
+    #[error("unsupported format {0:#04x}")]
+    UnsupportedFormat(u8),
+}
+
+impl From<io::Error> for DecodeError {
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::UnexpectedEof => DecodeError::TooShort,
+            kind => DecodeError::Io(kind),
+        }
+    }
+}
+
+/// Converts I/O errors back to their kind, and reports all other errors,
+/// including short reads, as [`io::ErrorKind::InvalidData`].
+impl From<DecodeError> for io::Error {
+    fn from(err: DecodeError) -> Self {
+        match err {
+            DecodeError::Io(kind) => io::Error::from(kind),
+            err => io::Error::new(io::ErrorKind::InvalidData, err),
+        }
+    }
+}
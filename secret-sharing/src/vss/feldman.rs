@@ -0,0 +1,150 @@
+//! Feldman verifiable secret sharing.
+//!
+//! The dealer hides the secret in the constant term of a random polynomial
+//! `P(x)` of degree `t`, hands out shares `P(k)` to shareholders `k = 1..n`,
+//! and publishes a verification matrix of the polynomial, viewed as
+//! a bivariate polynomial `B(x,y) = P(x)` of degree zero in `y`. Any `t + 1`
+//! shares suffice to reconstruct the secret.
+
+use group::{
+    ff::{Field, PrimeField},
+    Group,
+};
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use crate::poly::{BivariatePolynomial, SecretBivariatePolynomial};
+
+use super::{ShareId, VerificationMatrix};
+
+/// A share of a secret dealt by the Feldman scheme.
+#[derive(Clone)]
+pub struct Share<F: PrimeField> {
+    /// The identifier of the shareholder.
+    id: ShareId,
+    /// The value of the secret polynomial at the index.
+    value: F,
+}
+
+impl<F> Share<F>
+where
+    F: PrimeField,
+{
+    /// Creates a new share for the shareholder with the given identifier.
+    pub fn new(id: ShareId, value: F) -> Self {
+        Self { id, value }
+    }
+
+    /// Returns the identifier of the shareholder.
+    pub fn id(&self) -> ShareId {
+        self.id
+    }
+
+    /// Returns the index of the shareholder, starting at one.
+    pub fn index(&self) -> u64 {
+        self.id.get()
+    }
+
+    /// Returns the x-coordinate of the share, i.e., the identifier
+    /// as a field element.
+    pub fn x(&self) -> F {
+        self.id.to_scalar()
+    }
+
+    /// Returns the value of the share.
+    pub fn value(&self) -> &F {
+        &self.value
+    }
+
+    /// Verifies the share against the verification matrix of the secret
+    /// polynomial, i.e., if it holds `B(x,0) == v`.
+    pub fn verify<G>(&self, vm: &VerificationMatrix<G>) -> bool
+    where
+        G: Group<Scalar = F>,
+    {
+        vm.verify(&self.x(), &F::ZERO, &self.value)
+    }
+}
+
+impl<F> Zeroize for Share<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn zeroize(&mut self) {
+        // The identifier is public.
+        self.value.zeroize();
+    }
+}
+
+/// Deals the given secret into `num_shares` shares, any `threshold + 1`
+/// of which can reconstruct it, and returns the shares together with
+/// the verification matrix of the secret polynomial.
+///
+/// Shares are indexed from one, as the secret is the value at zero.
+pub fn deal<G>(
+    secret: G::Scalar,
+    threshold: u8,
+    num_shares: u64,
+    rng: &mut impl RngCore,
+) -> (Vec<Share<G::Scalar>>, VerificationMatrix<G>)
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    let bp = BivariatePolynomial::random_with_secret(threshold, 0, secret, rng);
+    let bp = SecretBivariatePolynomial::new(bp);
+
+    let shares = ShareId::range(num_shares)
+        .map(|id| Share::new(id, bp.eval(&id.to_scalar(), &G::Scalar::ZERO)))
+        .collect();
+    let vm = VerificationMatrix::from(&*bp);
+
+    (shares, vm)
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::poly::Polynomial;
+
+    use super::{deal, Share, ShareId};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    #[test]
+    fn test_deal() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = PrimeField::random(&mut rng);
+        let (shares, vm) = deal::<Group>(secret, 2, 5, &mut rng);
+
+        assert_eq!(shares.len(), 5);
+        assert_eq!(vm.dimensions(), (3, 1));
+        assert_eq!(vm.element(0, 0), Some(&(Group::generator() * secret)));
+
+        // All shares are valid.
+        for (k, share) in shares.iter().enumerate() {
+            assert_eq!(share.index(), k as u64 + 1);
+            assert!(share.verify(&vm));
+        }
+
+        // Tampered shares are invalid.
+        let id = ShareId::new(1).unwrap();
+        let share = Share::new(id, *shares[0].value() + PrimeField::ONE);
+        assert!(!share.verify(&vm));
+        let id = ShareId::new(2).unwrap();
+        let share = Share::new(id, *shares[0].value());
+        assert!(!share.verify(&vm));
+
+        // Any threshold + 1 shares reconstruct the secret.
+        let points: Vec<_> = shares.iter().map(|s| (s.x(), *s.value())).collect();
+        for subset in [&points[..3], &points[2..], &points[1..4]] {
+            let s = Polynomial::interpolate_at(subset, &PrimeField::ZERO).unwrap();
+            assert_eq!(s, secret);
+        }
+        let s = Polynomial::interpolate_at(&points[..2], &PrimeField::ZERO).unwrap();
+        assert_ne!(s, secret);
+    }
+}
@@ -2,7 +2,7 @@ use group::ff::PrimeField;
 use honggfuzz::fuzz;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
-use secret_sharing::{poly::BivariatePolynomial, vss::VerificationMatrix};
+use secret_sharing::api::{BivariatePolynomial, VerificationMatrix};
 
 fn main() {
     loop {
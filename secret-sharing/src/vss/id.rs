@@ -0,0 +1,117 @@
+use std::{fmt, num::NonZeroU64};
+
+use group::ff::PrimeField;
+
+/// The identifier of a shareholder, which determines the point at which
+/// the secret polynomial is evaluated to obtain the shareholder's share.
+///
+/// Identifiers start at one, as the secret is the value of the polynomial
+/// at zero, and a share evaluated there would reveal it. Since a zero
+/// identifier cannot be constructed, every identifier maps to a non-zero
+/// evaluation point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShareId(NonZeroU64);
+
+impl ShareId {
+    /// Creates a new identifier, returning `None` if the given value is zero.
+    pub fn new(id: u64) -> Option<Self> {
+        NonZeroU64::new(id).map(Self)
+    }
+
+    /// Creates an identifier for the shareholder at the given zero-based
+    /// position, e.g., in a list of shareholders, which is mapped to
+    /// the identifier `position + 1`.
+    ///
+    /// Returns `None` if the identifier would overflow.
+    pub fn from_position(position: u64) -> Option<Self> {
+        position.checked_add(1).and_then(Self::new)
+    }
+
+    /// Returns an iterator over the identifiers `1..=n`.
+    pub fn range(n: u64) -> impl DoubleEndedIterator<Item = Self> {
+        (1..=n).filter_map(Self::new)
+    }
+
+    /// Returns the identifier as an integer.
+    pub fn get(&self) -> u64 {
+        self.0.get()
+    }
+
+    /// Returns the zero-based position of the shareholder, i.e., the
+    /// identifier minus one.
+    pub fn position(&self) -> u64 {
+        self.0.get() - 1
+    }
+
+    /// Returns the evaluation point of the shareholder, i.e., the identifier
+    /// as an element of the prime field.
+    ///
+    /// The point is non-zero as long as the identifier is smaller than
+    /// the characteristic of the field, which holds for all fields
+    /// of cryptographic size.
+    pub fn to_scalar<F: PrimeField>(&self) -> F {
+        F::from(self.0.get())
+    }
+}
+
+impl From<ShareId> for u64 {
+    fn from(id: ShareId) -> Self {
+        id.get()
+    }
+}
+
+impl From<NonZeroU64> for ShareId {
+    fn from(id: NonZeroU64) -> Self {
+        Self(id)
+    }
+}
+
+impl fmt::Display for ShareId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShareId;
+
+    type PrimeField = p384::Scalar;
+
+    #[test]
+    fn test_new() {
+        assert!(ShareId::new(0).is_none());
+        assert_eq!(ShareId::new(1).map(|id| id.get()), Some(1));
+        assert_eq!(ShareId::new(u64::MAX).map(|id| id.get()), Some(u64::MAX));
+
+        assert_eq!(ShareId::from_position(0), ShareId::new(1));
+        assert_eq!(ShareId::from_position(4), ShareId::new(5));
+        assert!(ShareId::from_position(u64::MAX).is_none());
+
+        let id = ShareId::new(5).unwrap();
+        assert_eq!(id.position(), 4);
+        assert_eq!(u64::from(id), 5);
+        assert_eq!(id.to_string(), "5");
+    }
+
+    #[test]
+    fn test_range() {
+        let ids: Vec<_> = ShareId::range(3).map(|id| id.get()).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(ShareId::range(3).next_back(), ShareId::new(3));
+        assert_eq!(ShareId::range(0).count(), 0);
+    }
+
+    #[test]
+    fn test_to_scalar() {
+        for id in ShareId::range(10) {
+            let x: PrimeField = id.to_scalar();
+            assert!(!bool::from(x.is_zero()));
+            assert_eq!(x, PrimeField::from_u64(id.get()));
+        }
+
+        let id = ShareId::new(u64::MAX).unwrap();
+        let x: PrimeField = id.to_scalar();
+        assert_eq!(x, PrimeField::from_u64(u64::MAX));
+    }
+}
@@ -1,14 +1,182 @@
 use std::{
-    cmp::max,
-    ops::{Add, AddAssign},
+    borrow::Borrow,
+    cmp::{max, min},
+    fmt,
+    hash::{Hash, Hasher},
+    io::{self, Read},
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use group::{Group, GroupEncoding};
-use subtle::Choice;
+use group::{
+    ff::{Field, PrimeField},
+    prime::PrimeCurve,
+    Group, GroupEncoding,
+};
+use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use sha3::digest::{Digest, Output};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess, CtOption};
+
+use crate::poly::{
+    lagrange::basis_polynomials,
+    ops::{add_assign_slices, axpy, scale_slice, sub_assign_slices},
+    powers, powers_into, BivariatePolynomial, Polynomial,
+};
+
+use super::{
+    msm::msm, DecodeError, Error, GeneratorMul, GeneratorTable, PartialMatrix, PointEncoding,
+    PreparedVerificationMatrix, Sec1Encoding, SecretCommitment, SubgroupCheck,
+    UncompressedEncoding, VerificationContext, VerificationMatrixVerifier, VerificationVector,
+};
+
+/// The maximum number of rows or columns of a verification matrix, limited
+/// by the extended serialization format which encodes degrees as two bytes.
+const MAX_DIMENSION: usize = u16::MAX as usize + 1;
+
+/// The number of elements from which verification matrices are constructed
+/// using a precomputed generator table.
+const TABLE_THRESHOLD: usize = 8;
+
+/// The maximum number of rows or columns of a verification matrix that can
+/// be encoded in the legacy serialization format.
+const LEGACY_MAX_DIMENSION: usize = u8::MAX as usize + 1;
+
+/// The size of the legacy header, consisting of the degrees as single bytes.
+const LEGACY_HEADER_SIZE: usize = 2;
+
+/// The size of the extended header, consisting of the marker, the format tag,
+/// and the degrees as little-endian 16-bit integers.
+const EXTENDED_HEADER_SIZE: usize = 7;
+
+/// The marker at the start of an extended header.
+const EXTENDED_MARKER: [u8; 2] = [0xFF, 0xFF];
+
+/// The tag of the extended header for compressed elements.
+const COMPRESSED_TAG: u8 = 0x01;
+
+/// The tag of the extended header for uncompressed elements.
+const UNCOMPRESSED_TAG: u8 = 0x02;
+
+/// The maximum number of elements for which memory is reserved upfront
+/// when reading a verification matrix.
+const MAX_READ_CAPACITY: usize = 1 << 12;
+
+/// The separator between the header and the elements in the hex
+/// representation of a verification matrix.
+const HEX_SEPARATOR: char = ':';
+
+/// The context string of the verification matrix digest.
+const DIGEST_CONTEXT: &[u8] = b"oasis-core/secret-sharing: verification matrix digest v1";
+
+/// The byte representation format of a verification matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Degrees encoded as single bytes, which limits them to at most 255.
+    /// Elements are always compressed.
+    ///
+    /// Layout: `[deg_x, deg_y, elements...]`.
+    Legacy,
+    /// Degrees encoded as little-endian 16-bit integers, preceded by
+    /// a marker and a tag describing the encoding of the elements
+    /// (`0x01` for compressed, `0x02` for uncompressed).
+    ///
+    /// Layout: `[0xFF, 0xFF, tag, deg_x (2 bytes), deg_y (2 bytes), elements...]`.
+    Extended,
+}
+
+impl Format {
+    /// Returns the most compact format that can encode a matrix
+    /// of the given dimensions.
+    fn for_dimensions(rows: usize, cols: usize) -> Self {
+        if Format::Legacy.fits(rows, cols) {
+            Format::Legacy
+        } else {
+            Format::Extended
+        }
+    }
+
+    /// Returns true if and only if a matrix of the given dimensions can be
+    /// encoded in this format.
+    fn fits(&self, rows: usize, cols: usize) -> bool {
+        let max = match self {
+            Format::Legacy => LEGACY_MAX_DIMENSION,
+            Format::Extended => MAX_DIMENSION,
+        };
+        rows <= max && cols <= max
+    }
+
+    /// Returns the size of the header in this format.
+    pub(crate) fn header_size(&self) -> usize {
+        match self {
+            Format::Legacy => LEGACY_HEADER_SIZE,
+            Format::Extended => EXTENDED_HEADER_SIZE,
+        }
+    }
+
+    /// Returns the header encoding the given dimensions and element
+    /// encoding in this format.
+    ///
+    /// The dimensions must be non-zero and fit the format, and the legacy
+    /// format only supports compressed elements.
+    fn header(&self, encoding: PointEncoding, rows: usize, cols: usize) -> Vec<u8> {
+        match self {
+            Format::Legacy => {
+                debug_assert_eq!(encoding, PointEncoding::Compressed);
+                vec![(rows - 1) as u8, (cols - 1) as u8]
+            }
+            Format::Extended => {
+                let tag = match encoding {
+                    PointEncoding::Compressed => COMPRESSED_TAG,
+                    PointEncoding::Uncompressed => UNCOMPRESSED_TAG,
+                };
+                let mut header = Vec::with_capacity(EXTENDED_HEADER_SIZE);
+                header.extend_from_slice(&EXTENDED_MARKER);
+                header.push(tag);
+                header.extend_from_slice(&((rows - 1) as u16).to_le_bytes());
+                header.extend_from_slice(&((cols - 1) as u16).to_le_bytes());
+                header
+            }
+        }
+    }
+}
 
-use crate::poly::{powers, BivariatePolynomial, Polynomial};
+/// Options for strict decoding of verification matrices, as done
+/// by [`VerificationMatrix::from_bytes_strict`].
+///
+/// The default options impose no checks beyond those of
+/// [`VerificationMatrix::try_from_bytes`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DecodeOptions {
+    /// Whether elements must be encoded canonically, i.e., exactly as they
+    /// are re-encoded.
+    pub require_canonical: bool,
+    /// The positions `(i, j)` of the elements that must not be the identity.
+    /// Positions outside the matrix are ignored.
+    pub forbid_identity: Vec<(usize, usize)>,
+    /// Whether the leading row, i.e., the row of `x^{deg_x}`, must contain
+    /// an element other than the identity, so that the degree in `x`
+    /// is exact.
+    pub forbid_identity_leading_row: bool,
+    /// Whether the leading column, i.e., the column of `y^{deg_y}`, must
+    /// contain an element other than the identity, so that the degree in `y`
+    /// is exact.
+    pub forbid_identity_leading_column: bool,
+}
 
-use super::VerificationVector;
+impl DecodeOptions {
+    /// Returns options enabling all checks, with the identity forbidden
+    /// at `M_{0,0}`, as expected of a fresh dealing.
+    pub fn strict() -> Self {
+        Self {
+            require_canonical: true,
+            forbid_identity: vec![(0, 0)],
+            forbid_identity_leading_row: true,
+            forbid_identity_leading_column: true,
+        }
+    }
+}
 
 /// Verification matrix for a bivariate polynomial.
 ///
@@ -25,6 +193,19 @@ use super::VerificationVector;
 /// ```text
 ///     B(x,y) = \sum_{i=0}^{deg_x} \sum_{j=0}^{deg_y} b_{i,j} x^i y^j
 /// ```
+///
+/// A verification matrix always has at least one row and one column,
+/// and all rows are of equal length. Every constructor enforces this,
+/// so methods may access `M_{0,0}` and rely on `rows >= 1` and `cols >= 1`.
+///
+/// Equality is structural, so matrices padded with identity elements differ
+/// from the original ones. Use [`VerificationMatrix::eq_normalized`]
+/// to compare matrices regardless of trailing identity rows and columns.
+///
+/// A verification matrix is public and deliberately doesn't implement
+/// `Zeroize`. The secret lives only in the bivariate polynomial, which
+/// should be held in a [`SecretBivariatePolynomial`](crate::poly::SecretBivariatePolynomial)
+/// by the dealer.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VerificationMatrix<G: Group> {
     /// The number of rows in the verification matrix, determined by
@@ -35,57 +216,530 @@ pub struct VerificationMatrix<G: Group> {
     /// the degree of the bivariate polynomial in the `y` variable from
     /// which the matrix was constructed.
     pub(crate) cols: usize,
-    /// The verification matrix elements, where `m[i][j]` represents
-    /// the element `b_{i,j} * G`.
-    pub(crate) m: Vec<Vec<G>>,
+    /// The verification matrix elements in row-major order, where
+    /// `m[i * cols + j]` represents the element `b_{i,j} * G`.
+    pub(crate) m: Vec<G>,
 }
 
 impl<G> VerificationMatrix<G>
 where
     G: Group,
 {
+    /// Creates a verification matrix with the given elements, where `m[i][j]`
+    /// represents the element `b_{i,j} * G`.
+    ///
+    /// Returns an error if the matrix is empty, its rows are not of equal
+    /// length, or its dimensions exceed the limits of the serialization
+    /// format.
+    pub fn new(m: Vec<Vec<G>>) -> Result<Self, Error> {
+        let rows = m.len();
+        let cols = m.first().map(Vec::len).unwrap_or_default();
+
+        if m.iter().any(|mi| mi.len() != cols) {
+            return Err(Error::RaggedMatrix);
+        }
+
+        Self::from_flat(rows, cols, m.into_iter().flatten().collect())
+    }
+
+    /// Creates a verification matrix of the given dimensions consisting
+    /// of identity elements, i.e., the verification matrix of the zero
+    /// polynomial.
+    ///
+    /// Adding a matrix to it yields that matrix padded to the larger
+    /// dimensions, so it can serve as a neutral starting accumulator.
+    ///
+    /// Returns an error if either dimension is zero or exceeds the limits
+    /// of the serialization format.
+    pub fn zero(rows: usize, cols: usize) -> Result<Self, Error> {
+        if rows > MAX_DIMENSION || cols > MAX_DIMENSION {
+            return Err(Error::DimensionsTooLarge);
+        }
+
+        Self::from_flat(rows, cols, vec![G::identity(); rows * cols])
+    }
+
+    /// Creates a verification matrix with the given dimensions from
+    /// the given elements in row-major order.
+    ///
+    /// Returns an error if the matrix is empty, the number of elements
+    /// doesn't match the dimensions, or the dimensions exceed the limits
+    /// of the serialization format.
+    fn from_flat(rows: usize, cols: usize, m: Vec<G>) -> Result<Self, Error> {
+        if rows == 0 || cols == 0 {
+            return Err(Error::EmptyMatrix);
+        }
+        if m.len() != rows * cols {
+            return Err(Error::RaggedMatrix);
+        }
+        if rows > MAX_DIMENSION || cols > MAX_DIMENSION {
+            return Err(Error::DimensionsTooLarge);
+        }
+
+        Ok(Self { rows, cols, m })
+    }
+
     /// Returns the dimensions (number of rows and columns) of the verification
     /// matrix.
     pub fn dimensions(&self) -> (usize, usize) {
         (self.rows, self.cols)
     }
 
+    /// Returns the degree of the underlying bivariate polynomial
+    /// in the `x` variable, i.e., the number of rows minus one.
+    pub fn deg_x(&self) -> usize {
+        self.rows - 1
+    }
+
+    /// Returns the degree of the underlying bivariate polynomial
+    /// in the `y` variable, i.e., the number of columns minus one.
+    pub fn deg_y(&self) -> usize {
+        self.cols - 1
+    }
+
     /// Returns the element `m_{i,j}` of the verification matrix.
     pub fn element(&self, i: usize, j: usize) -> Option<&G> {
-        self.m.get(i).and_then(|bi| bi.get(j))
+        if i >= self.rows || j >= self.cols {
+            return None;
+        }
+        Some(&self.m[i * self.cols + j])
+    }
+
+    /// Returns a mutable reference to the element `m_{i,j}` of the verification
+    /// matrix.
+    pub fn element_mut(&mut self, i: usize, j: usize) -> Option<&mut G> {
+        if i >= self.rows || j >= self.cols {
+            return None;
+        }
+        Some(&mut self.m[i * self.cols + j])
+    }
+
+    /// Returns the `i`-th row of the verification matrix, i.e., the
+    /// verification vector for the coefficients of `x^i` in `B(x,y)`,
+    /// or `None` if the row doesn't exist.
+    pub fn row(&self, i: usize) -> Option<VerificationVector<G>> {
+        if i >= self.rows {
+            return None;
+        }
+        let mi = &self.m[i * self.cols..(i + 1) * self.cols];
+        Some(VerificationVector::new(mi.to_vec()))
+    }
+
+    /// Returns the first row of the verification matrix, i.e., the Feldman
+    /// commitment `[M_{0,j}]` to the polynomial `B(0,y)`.
+    ///
+    /// Recipients that only verify shares `B(0,y)` of the secret don't
+    /// need the whole matrix, as [`VerificationVector::verify`] checks
+    /// `B(0,y) == v` using this row alone.
+    pub fn first_row_commitment(&self) -> VerificationVector<G> {
+        self.row(0).expect("matrix should not be empty")
+    }
+
+    /// Returns the commitment `M_{0,0} = s * G` to the secret `s = B(0,0)`.
+    pub fn secret_commitment(&self) -> SecretCommitment<G> {
+        SecretCommitment::new(self.m[0])
+    }
+
+    /// Returns the `j`-th column of the verification matrix, i.e., the
+    /// verification vector for the coefficients of `y^j` in `B(x,y)`,
+    /// or `None` if the column doesn't exist.
+    pub fn column(&self, j: usize) -> Option<VerificationVector<G>> {
+        if j >= self.cols {
+            return None;
+        }
+        let v = self.column_iter(j).cloned().collect();
+        Some(VerificationVector::new(v))
+    }
+
+    /// Returns an iterator over the elements of the verification matrix
+    /// together with their positions, yielding `(i, j, M_{i,j})` in row-major
+    /// order, which is the order of the byte representation.
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (usize, usize, &G)> + DoubleEndedIterator + '_ {
+        let cols = self.cols;
+        self.m
+            .iter()
+            .enumerate()
+            .map(move |(k, mij)| (k / cols, k % cols, mij))
+    }
+
+    /// Returns an iterator over the rows of the verification matrix
+    /// as slices.
+    pub fn rows_iter(&self) -> impl ExactSizeIterator<Item = &[G]> + DoubleEndedIterator + '_ {
+        self.m.chunks(self.cols)
+    }
+
+    /// Returns an iterator over the elements of the `j`-th column,
+    /// which is empty if the column doesn't exist.
+    pub fn column_iter(&self, j: usize) -> impl Iterator<Item = &G> + '_ {
+        let skip = match j < self.cols {
+            true => j,
+            false => self.m.len(),
+        };
+        self.m.iter().skip(skip).step_by(self.cols)
+    }
+
+    /// Returns the elements of the verification matrix padded with
+    /// identity elements to the given dimensions, in row-major order.
+    ///
+    /// The dimensions must not be smaller than the current ones.
+    fn padded(&self, rows: usize, cols: usize) -> Vec<G> {
+        debug_assert!(rows >= self.rows && cols >= self.cols);
+
+        let mut m = vec![G::identity(); rows * cols];
+        for (ai, bi) in m.chunks_mut(cols).zip(self.rows_iter()) {
+            ai[..bi.len()].copy_from_slice(bi);
+        }
+
+        m
+    }
+
+    /// Returns the transposed verification matrix, i.e., the verification
+    /// matrix for the bivariate polynomial `B(y,x)`.
+    pub fn transpose(&self) -> VerificationMatrix<G> {
+        let mut m = Vec::with_capacity(self.m.len());
+        for j in 0..self.cols {
+            m.extend(self.column_iter(j));
+        }
+
+        VerificationMatrix::from_flat(self.cols, self.rows, m).expect("dimensions should be valid")
+    }
+
+    /// Returns the verification matrix for the bivariate polynomial
+    /// truncated to the given degrees, i.e., the top-left
+    /// `(deg_x + 1) x (deg_y + 1)` sub-matrix.
+    ///
+    /// Returns an error if a requested degree exceeds the current one.
+    pub fn truncate(&self, deg_x: usize, deg_y: usize) -> Result<VerificationMatrix<G>, Error> {
+        if deg_x >= self.rows || deg_y >= self.cols {
+            return Err(Error::InvalidDegrees);
+        }
+
+        let m = self
+            .rows_iter()
+            .take(deg_x + 1)
+            .flat_map(|mi| mi[..=deg_y].iter().copied())
+            .collect();
+
+        VerificationMatrix::from_flat(deg_x + 1, deg_y + 1, m)
+    }
+
+    /// Returns the verification matrix extended to the given degrees,
+    /// where the new elements are the identity, i.e., the verification
+    /// matrix for the same bivariate polynomial with zero coefficients
+    /// added for the higher degrees.
+    ///
+    /// Returns an error if a requested degree is lower than the current one,
+    /// or if the resulting dimensions exceed the limits of the serialization
+    /// format.
+    pub fn extend_zero(&self, deg_x: usize, deg_y: usize) -> Result<VerificationMatrix<G>, Error> {
+        if deg_x + 1 < self.rows || deg_y + 1 < self.cols {
+            return Err(Error::InvalidDegrees);
+        }
+        if deg_x >= MAX_DIMENSION || deg_y >= MAX_DIMENSION {
+            return Err(Error::DimensionsTooLarge);
+        }
+
+        let m = self.padded(deg_x + 1, deg_y + 1);
+
+        VerificationMatrix::from_flat(deg_x + 1, deg_y + 1, m)
+    }
+
+    /// Strips trailing rows and columns consisting of identity elements,
+    /// keeping at least one row and one column.
+    ///
+    /// The normalized matrix is the verification matrix of the same
+    /// bivariate polynomial with its degrees reduced to the actual ones,
+    /// so verification results are unchanged. This undoes the padding
+    /// introduced by adding matrices of different dimensions.
+    pub fn normalize(&mut self) {
+        let mut rows = 1;
+        let mut cols = 1;
+        for (i, j, mij) in self.iter() {
+            if !bool::from(mij.is_identity()) {
+                rows = max(rows, i + 1);
+                cols = max(cols, j + 1);
+            }
+        }
+
+        if rows != self.rows || cols != self.cols {
+            *self = self
+                .truncate(rows - 1, cols - 1)
+                .expect("degrees should be valid");
+        }
+    }
+
+    /// Returns the normalized verification matrix, as produced
+    /// by [`Self::normalize`].
+    pub fn normalized(&self) -> Self {
+        let mut vm = self.clone();
+        vm.normalize();
+        vm
+    }
+
+    /// Returns true if and only if the verification matrices are equal
+    /// after normalization, i.e., if they belong to the same bivariate
+    /// polynomial, regardless of trailing identity rows and columns.
+    ///
+    /// Unlike this method, the derived [`PartialEq`] compares matrices
+    /// structurally, so matrices of different dimensions are never equal.
+    pub fn eq_normalized(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+
+    /// Adds the given verification matrix, as [`Add`] does, but returns
+    /// an error if the dimensions of the matrices differ instead of padding
+    /// the smaller one.
+    pub fn checked_add(&self, rhs: &VerificationMatrix<G>) -> Result<Self, Error> {
+        self.check_dimensions(rhs)?;
+        Ok(self + rhs)
+    }
+
+    /// Adds the given verification matrix in place, as [`AddAssign`] does,
+    /// but returns an error if the dimensions of the matrices differ instead
+    /// of padding the smaller one, in which case the matrix is left unchanged.
+    pub fn checked_add_assign(&mut self, rhs: &VerificationMatrix<G>) -> Result<(), Error> {
+        self.check_dimensions(rhs)?;
+        *self += rhs;
+        Ok(())
+    }
+
+    /// Returns an error if the dimensions of the given verification matrix
+    /// differ.
+    fn check_dimensions(&self, rhs: &VerificationMatrix<G>) -> Result<(), Error> {
+        if self.dimensions() != rhs.dimensions() {
+            return Err(Error::DimensionMismatch {
+                lhs: self.dimensions(),
+                rhs: rhs.dimensions(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns true if and only if the verification matrix is symmetric,
+    /// i.e., if it holds `M_{i,j} == M_{j,i}` for all `i` and `j`.
+    ///
+    /// This method is not constant time if the matrix is not square.
+    pub fn is_symmetric(&self) -> bool {
+        // Short-circuit on the dimensions, not the elements.
+        if self.rows != self.cols {
+            return false;
+        }
+
+        // Don't short-circuit this loop to avoid revealing which element
+        // differs. Group elements are compared by checking whether their
+        // difference is the identity, which is constant time.
+        let mut symmetric = Choice::from(1);
+        for i in 0..self.rows {
+            for j in (i + 1)..self.cols {
+                let mij = self.m[i * self.cols + j];
+                let mji = self.m[j * self.cols + i];
+                symmetric &= (mij - mji).is_identity();
+            }
+        }
+
+        symmetric.into()
     }
 
     /// Returns true if and only if `M_{0,0}` is the identity element
     /// of the group.
+    ///
+    /// The element `M_{0,0}` always exists as verification matrices
+    /// are never empty.
+    ///
+    /// This only checks that the secret `B(0,0)` is zero. Use
+    /// [`Self::is_zero`] to check that the whole polynomial is zero.
     pub fn is_zero_hole(&self) -> bool {
-        self.m[0][0].is_identity().into()
+        self.is_zero_hole_ct().into()
+    }
+
+    /// Returns a choice indicating whether `M_{0,0}` is the identity element
+    /// of the group, for composition with other constant-time checks.
+    pub fn is_zero_hole_ct(&self) -> Choice {
+        self.m[0].is_identity()
+    }
+
+    /// Returns true if and only if all elements of the verification matrix
+    /// are the identity element of the group, i.e., the underlying
+    /// bivariate polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.is_zero_ct().into()
+    }
+
+    /// Returns a choice indicating whether all elements of the verification
+    /// matrix are the identity element of the group, for composition with
+    /// other constant-time checks.
+    ///
+    /// All elements are checked without short-circuiting.
+    pub fn is_zero_ct(&self) -> Choice {
+        self.m
+            .iter()
+            .fold(Choice::from(1), |acc, mij| acc & mij.is_identity())
     }
 
     /// Verifies whether the underlying bivariate polynomial evaluates
     /// to the given value, i.e., if it holds `B(x,y) == v`.
     pub fn verify(&self, x: &G::Scalar, y: &G::Scalar, v: &G::Scalar) -> bool {
+        self.verify_ct(x, y, v).into()
+    }
+
+    /// Verifies whether the underlying bivariate polynomial evaluates
+    /// to the given value, as [`Self::verify`] does, but returns a choice
+    /// for composition with other constant-time checks.
+    ///
+    /// The value is handled in constant time. The point `(x,y)` is assumed
+    /// to be public, as the multi-scalar multiplication over the powers
+    /// of its coordinates is not constant time.
+    pub fn verify_ct(&self, x: &G::Scalar, y: &G::Scalar, v: &G::Scalar) -> Choice {
+        self.verify_at(x, y, &G::mul_generator(v))
+    }
+
+    /// Verifies whether the underlying bivariate polynomial evaluates
+    /// to the given value, i.e., if it holds `B(x,y) == v`, for a matrix
+    /// whose elements commit to the coefficients against the given base,
+    /// i.e., `M_{i,j} = b_{i,j} * H`.
+    ///
+    /// See [`Self::from_with_base`] for constructing such matrices.
+    pub fn verify_with_base(&self, base: &G, x: &G::Scalar, y: &G::Scalar, v: &G::Scalar) -> bool {
+        self.verify_at(x, y, &(*base * v)).into()
+    }
+
+    /// Verifies whether `\sum_{i,j} x^i * y^j * M_{i,j} == v * H` for the given
+    /// commitment `v * H` to the value.
+    fn verify_at(&self, x: &G::Scalar, y: &G::Scalar, vh: &G) -> Choice {
+        let xpows = powers(x, self.deg_x()); // [x^i]
+        let ypows = powers(y, self.deg_y()); // [y^j]
+        let mut scalars = Vec::with_capacity(self.rows * self.cols);
+        for xpow in xpows {
+            scalars.extend(ypows.iter().map(|ypow| xpow * ypow)); // x^i * y^j
+        }
+
+        self.verify_scalars(&scalars, vh)
+    }
+
+    /// Verifies whether the underlying bivariate polynomial evaluates
+    /// to the given value at the point of the given context, i.e., if it
+    /// holds `B(x,y) == v`.
+    ///
+    /// Unlike [`Self::verify`], the powers of `x` and `y` are taken from
+    /// the context, so that repeated checks at the same point don't
+    /// recompute them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degrees of the matrix exceed those of the context.
+    pub fn verify_with_context(
+        &self,
+        context: &VerificationContext<G::Scalar>,
+        v: &G::Scalar,
+    ) -> bool {
+        let scalars = context.scalars(self.rows, self.cols); // [x^i * y^j]
+        self.verify_scalars(&scalars, &G::mul_generator(v)).into()
+    }
+
+    /// Verifies whether `\sum_{i,j} s_{i,j} * M_{i,j} == v * H` for the given
+    /// scalars `s_{i,j}` in row-major order and the given commitment `v * H`
+    /// to the value.
+    fn verify_scalars(&self, scalars: &[G::Scalar], vh: &G) -> Choice {
+        // The value may be secret, so its commitment is computed by the caller
+        // outside of the multi-scalar multiplication, which is not constant time.
+        let mut diff = msm(scalars, &self.m); // \sum_{i,j} s_{i,j} * M_{i,j}
+        diff -= vh;
+
+        diff.is_identity()
+    }
+
+    /// Verifies whether the underlying bivariate polynomial evaluates
+    /// to the given value, i.e., if it holds `B(x,y) == v`, by multiplying
+    /// each matrix element separately.
+    #[cfg(test)]
+    fn verify_naive(&self, x: &G::Scalar, y: &G::Scalar, v: &G::Scalar) -> bool {
         let mut diff = G::generator().neg() * v;
-        let xpows = powers(x, self.rows - 1); // [x^i]
-        let ypows = powers(y, self.cols - 1); // [y^j]
+        let xpows = powers(x, self.deg_x()); // [x^i]
+        let ypows = powers(y, self.deg_y()); // [y^j]
         for (i, xpow) in xpows.into_iter().enumerate() {
             for (j, ypow) in ypows.iter().enumerate() {
-                diff += self.m[i][j] * (xpow * ypow); // x^i * y^j * M_{i,j} = b_{i,j} x^i * y^j * G
+                diff += self.m[i * self.cols + j] * (xpow * ypow); // x^i * y^j * M_{i,j} = b_{i,j} x^i * y^j * G
+            }
+        }
+
+        diff.is_identity().into()
+    }
+
+    /// Verifies whether the underlying bivariate polynomial evaluates
+    /// to the given values, i.e., if it holds `B(x_k,y_k) == v_k` for all
+    /// points `(x_k, y_k, v_k)`.
+    ///
+    /// All checks are folded into one using a random linear combination
+    /// of the per-point differences:
+    /// ```text
+    /// \sum_k r_k (B(x_k,y_k) - v_k) * G
+    ///     = \sum_{i,j} (\sum_k r_k x_k^i y_k^j) * M_{i,j} - (\sum_k r_k v_k) * G
+    /// ```
+    /// so that the whole batch collapses into a single multi-scalar
+    /// multiplication over the matrix elements. The challenges
+    /// `r_k` are sampled from the given RNG after the points are fixed,
+    /// so an adversary cannot craft invalid points whose differences cancel
+    /// out, except with negligible probability.
+    ///
+    /// Use [`Self::find_invalid_point`] to locate an invalid point.
+    pub fn verify_batch(
+        &self,
+        points: &[(G::Scalar, G::Scalar, G::Scalar)],
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> bool {
+        match points {
+            [] => return true,
+            [(x, y, v)] => return self.verify(x, y, v),
+            _ => (),
+        }
+
+        // Compute the coefficients c_{i,j} = \sum_k r_k x_k^i y_k^j
+        // in row-major order and the combined value \sum_k r_k v_k.
+        let mut c = vec![G::Scalar::ZERO; self.rows * self.cols];
+        let mut v = G::Scalar::ZERO;
+        let mut xpows = Vec::with_capacity(self.rows);
+        let mut ypows = Vec::with_capacity(self.cols);
+        for (x, y, vk) in points {
+            let r = G::Scalar::random(&mut *rng);
+            powers_into(x, self.deg_x(), &mut xpows); // [x^i]
+            powers_into(y, self.deg_y(), &mut ypows); // [y^j]
+            for (ci, xpow) in c.chunks_mut(self.cols).zip(xpows.iter()) {
+                axpy(ci, &(r * xpow), &ypows); // r_k x_k^i y_k^j
             }
+            v += r * vk;
         }
+        // The combined value may be secret, so keep it out of the multi-scalar
+        // multiplication, which is not constant time.
+        let mut diff = msm(&c, &self.m); // \sum_{i,j} c_{i,j} * M_{i,j}
+        diff -= G::mul_generator(&v);
 
         diff.is_identity().into()
     }
 
+    /// Returns the index of the first point `(x_k, y_k, v_k)` for which
+    /// `B(x_k,y_k) != v_k`, if any.
+    ///
+    /// This method verifies the points one by one and is meant to be used
+    /// after a failed [`Self::verify_batch`].
+    pub fn find_invalid_point(
+        &self,
+        points: &[(G::Scalar, G::Scalar, G::Scalar)],
+    ) -> Option<usize> {
+        points.iter().position(|(x, y, v)| !self.verify(x, y, v))
+    }
+
     /// Returns a verification vector for the univariate polynomial resulting
     /// from the evaluation of the underlying bivariate polynomial `B(x,y)`
     /// at the given `y` value.
     pub fn verification_vector_for_x(&self, y: &G::Scalar) -> VerificationVector<G> {
         let mut v = Vec::with_capacity(self.rows);
-        let ypows = powers(y, self.cols - 1); // [y^i]
-        for i in 0..self.rows {
+        let ypows = powers(y, self.deg_y()); // [y^i]
+        for mi in self.rows_iter() {
             let mut vi = G::identity();
-            for (j, ypow) in ypows.iter().enumerate() {
-                vi += self.m[i][j] * ypow;
+            for (mij, ypow) in mi.iter().zip(ypows.iter()) {
+                vi += *mij * ypow;
             }
             v.push(vi);
         }
@@ -93,16 +747,36 @@ where
         VerificationVector::new(v)
     }
 
+    /// Returns verification vectors for the univariate polynomials resulting
+    /// from the evaluation of the underlying bivariate polynomial `B(x,y)`
+    /// at each of the given `y` values.
+    ///
+    /// The result equals calling [`Self::verification_vector_for_x`] for each
+    /// value, but every vector element is computed with a single multi-scalar
+    /// multiplication over a row of the matrix:
+    /// ```text
+    ///     V_i = \sum_{j=0}^{deg_y} y^j * M_{i,j}
+    /// ```
+    pub fn verification_vectors_for_x(&self, ys: &[G::Scalar]) -> Vec<VerificationVector<G>> {
+        ys.iter()
+            .map(|y| {
+                let ypows = powers(y, self.deg_y()); // [y^j]
+                let v = self.rows_iter().map(|mi| msm(&ypows, mi)).collect();
+                VerificationVector::new(v)
+            })
+            .collect()
+    }
+
     /// Returns a verification vector for the univariate polynomial resulting
     /// from the evaluation of the underlying bivariate polynomial `B(x,y)`
     /// at the given `x` value.
     pub fn verification_vector_for_y(&self, x: &G::Scalar) -> VerificationVector<G> {
         let mut v = Vec::with_capacity(self.cols);
-        let xpows = powers(x, self.rows - 1); // [x^i]
+        let xpows = powers(x, self.deg_x()); // [x^i]
         for j in 0..self.cols {
             let mut vj = G::identity();
             for (i, xpow) in xpows.iter().enumerate() {
-                vj += self.m[i][j] * xpow;
+                vj += self.m[i * self.cols + j] * xpow;
             }
             v.push(vj);
         }
@@ -110,6 +784,70 @@ where
         VerificationVector::new(v)
     }
 
+    /// Reconstructs the verification matrix from the verification vectors
+    /// `V_k = verification_vector_for_y(x_k)` at the given points `x_k`.
+    ///
+    /// Each column of the matrix holds the coefficients of a polynomial
+    /// in `x` whose evaluations are given by the elements of the vectors:
+    /// ```text
+    ///     V_{k,j} = \sum_{i=0}^{deg_x} x_k^i * M_{i,j}
+    /// ```
+    /// so the columns are recovered by Lagrange interpolation in the exponent:
+    /// ```text
+    ///     M_{i,j} = \sum_k l_{k,i} * V_{k,j}
+    /// ```
+    /// where `l_{k,i}` is the coefficient of `x^i` in the `k`-th Lagrange
+    /// basis polynomial. The number of rows of the matrix equals the number
+    /// of points.
+    ///
+    /// Returns `None` if there are no points, the x-coordinates are not
+    /// unique, or the vectors are empty or of different lengths.
+    pub fn interpolate_rows(points: &[(G::Scalar, VerificationVector<G>)]) -> Option<Self> {
+        let rows = points.len();
+        let cols = points.first()?.1.v.len();
+        if cols == 0 || points.iter().any(|(_, vv)| vv.v.len() != cols) {
+            return None;
+        }
+
+        let xs: Vec<_> = points.iter().map(|(x, _)| *x).collect();
+        for (k, x) in xs.iter().enumerate() {
+            if xs[..k].contains(x) {
+                return None;
+            }
+        }
+
+        let ls = basis_polynomials(&xs);
+        let columns: Vec<Vec<G>> = (0..cols)
+            .map(|j| points.iter().map(|(_, vv)| vv.v[j]).collect())
+            .collect();
+
+        let mut m = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            let scalars: Vec<_> = ls.iter().map(|lk| coefficient_or_zero(lk, i)).collect(); // [l_{k,i}]
+            for column in &columns {
+                m.push(msm(&scalars, column)); // \sum_k l_{k,i} * V_{k,j}
+            }
+        }
+
+        Self::from_flat(rows, cols, m).ok()
+    }
+
+    /// Returns a verifier for repeated checks of values `B(x,y)`
+    /// at the given `x` value.
+    pub fn prepare_x(&self, x: &G::Scalar) -> VerificationMatrixVerifier<G> {
+        let vv = self.verification_vector_for_y(x);
+        VerificationMatrixVerifier::new(*x, vv)
+    }
+
+    /// Returns the verification matrix with its elements converted to affine
+    /// representation, for cheaper repeated verification.
+    pub fn prepare(&self) -> PreparedVerificationMatrix<G>
+    where
+        G: PrimeCurve,
+    {
+        PreparedVerificationMatrix::new(self)
+    }
+
     /// Verifies coefficients of the polynomial resulting from the evaluation
     /// of the bivariate polynomial with respect to the indeterminate x against
     /// the verification matrix.
@@ -130,7 +868,21 @@ where
     ///
     /// This method is not constant time if the size of the polynomial
     /// is invalid.
+    ///
+    /// Supported suites multiply the generator using a cached
+    /// [`GeneratorTable`], verifiers in other groups can share one,
+    /// see [`Self::verify_x_with_table`].
     pub fn verify_x(&self, x: &G::Scalar, polynomial: &Polynomial<G::Scalar>) -> bool {
+        self.verify_x_with(x, polynomial, G::mul_generator)
+    }
+
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate x,
+    /// where the elements `a_j * G` are computed by the given function.
+    fn verify_x_with<F>(&self, x: &G::Scalar, polynomial: &Polynomial<G::Scalar>, mul: F) -> bool
+    where
+        F: Fn(&G::Scalar) -> G,
+    {
         // Short-circuit on the size of the polynomial, not its contents.
         if polynomial.size() != self.cols {
             return false;
@@ -138,17 +890,18 @@ where
 
         // Don't short-circuit this loop to avoid revealing which coefficient
         // failed to verify.
-        let xpows = powers(x, self.rows - 1); // [x^i]
+        let xpows = powers(x, self.deg_x()); // [x^i]
         let mut verified = Choice::from(1);
 
         for j in 0..self.cols {
             // Verify if the following difference is the identity element (zero)
             // of the group: a_j * G - \sum_{i=0}^{deg_x} x^i * M_{i,j}.
+            // The coefficient is secret, so keep it out of the multi-scalar
+            // multiplication, which is not constant time.
             let aj = polynomial.coefficient(j).expect("size checked above");
-            let mut diff = G::generator() * aj; // a_j * G
-            for (i, xpow) in xpows.iter().enumerate() {
-                diff -= self.m[i][j] * xpow; // x^i * M_{i,j} = b_{i,j} x^i * G
-            }
+            let mj: Vec<_> = self.column_iter(j).copied().collect(); // [M_{i,j}]
+            let mut diff = mul(aj); // a_j * G
+            diff -= msm(&xpows, &mj); // \sum_i x^i * M_{i,j} = \sum_i b_{i,j} x^i * G
 
             verified &= diff.is_identity();
         }
@@ -177,7 +930,21 @@ where
     ///
     /// This method is not constant time if the size of the polynomial
     /// is invalid.
+    ///
+    /// Supported suites multiply the generator using a cached
+    /// [`GeneratorTable`], verifiers in other groups can share one,
+    /// see [`Self::verify_y_with_table`].
     pub fn verify_y(&self, y: &G::Scalar, polynomial: &Polynomial<G::Scalar>) -> bool {
+        self.verify_y_with(y, polynomial, G::mul_generator)
+    }
+
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate y,
+    /// where the elements `a_i * G` are computed by the given function.
+    fn verify_y_with<F>(&self, y: &G::Scalar, polynomial: &Polynomial<G::Scalar>, mul: F) -> bool
+    where
+        F: Fn(&G::Scalar) -> G,
+    {
         // Short-circuit on the size of the polynomial, not its contents.
         if polynomial.size() != self.rows {
             return false;
@@ -185,401 +952,3457 @@ where
 
         // Don't short-circuit this loop to avoid revealing which coefficient
         // failed to verify.
-        let ypows = powers(y, self.cols - 1); // [y^j]
+        let ypows = powers(y, self.deg_y()); // [y^j]
         let mut verified = Choice::from(1);
 
-        for i in 0..self.rows {
+        for (i, mi) in self.rows_iter().enumerate() {
             // Verify if the following difference is the identity element (zero)
             // of the group: a_i * G - \sum_{j=0}^{deg_y} y^j * M_{i,j}.
+            // The coefficient is secret, so keep it out of the multi-scalar
+            // multiplication, which is not constant time.
             let ai = polynomial.coefficient(i).expect("size checked above");
-            let mut diff = G::generator() * ai; // a_i * G
-            for (j, ypow) in ypows.iter().enumerate() {
-                diff -= self.m[i][j] * ypow; // y^j * M_{i,j} = b_{i,j} y^j * G
-            }
+            let mut diff = mul(ai); // a_i * G
+            diff -= msm(&ypows, mi); // \sum_j y^j * M_{i,j} = \sum_j b_{i,j} y^j * G
 
             verified &= diff.is_identity();
         }
 
         verified.into()
     }
-}
-
-impl<G> VerificationMatrix<G>
-where
-    G: Group + GroupEncoding,
-{
-    /// Returns the byte representation of the verification matrix.
-    pub fn to_bytes(&self) -> Vec<u8> {
-        let cap = Self::byte_size(self.rows, self.cols);
-        let mut bytes = Vec::with_capacity(cap);
-        let deg_x = (self.rows - 1) as u8;
-        let deg_y = (self.cols - 1) as u8;
-        bytes.extend([deg_x, deg_y].iter());
-        for mi in &self.m {
-            for mij in mi {
-                bytes.extend_from_slice(mij.to_bytes().as_ref());
-            }
-        }
 
-        bytes
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate x against
+    /// the verification matrix, as [`Self::verify_x`] does, and returns
+    /// the index `j` of the first coefficient that fails to verify.
+    ///
+    /// If the size of the polynomial is invalid, the returned index is
+    /// the first one at which a coefficient is missing or in excess.
+    ///
+    /// Warning: This method is not constant time, as it stops at the first
+    /// invalid coefficient, and reveals which one it is. It is intended only
+    /// for debugging dealer implementations in tests, and must not be used
+    /// to verify shares in production, where [`Self::verify_x`] should be
+    /// used instead.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn verify_x_debug(
+        &self,
+        x: &G::Scalar,
+        polynomial: &Polynomial<G::Scalar>,
+    ) -> Result<(), usize> {
+        let xpows = powers(x, self.deg_x()); // [x^i]
+
+        for j in 0..max(self.cols, polynomial.size()) {
+            let aj = polynomial.coefficient(j).ok_or(j)?;
+            let mj: Vec<_> = self.column_iter(j).copied().collect(); // [M_{i,j}]
+            if mj.is_empty() || G::mul_generator(aj) != msm(&xpows, &mj) {
+                return Err(j);
+            }
+        }
+
+        Ok(())
     }
 
-    /// Attempts to create a verification matrix from its byte representation.
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate y against
+    /// the verification matrix, as [`Self::verify_y`] does, and returns
+    /// the index `i` of the first coefficient that fails to verify.
     ///
-    /// This method is not constant time since the verification matrix doesn't
-    /// contain sensitive information.
-    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
-        if bytes.len() < 2 {
-            return None;
+    /// If the size of the polynomial is invalid, the returned index is
+    /// the first one at which a coefficient is missing or in excess.
+    ///
+    /// Warning: This method is not constant time, as it stops at the first
+    /// invalid coefficient, and reveals which one it is. It is intended only
+    /// for debugging dealer implementations in tests, and must not be used
+    /// to verify shares in production, where [`Self::verify_y`] should be
+    /// used instead.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn verify_y_debug(
+        &self,
+        y: &G::Scalar,
+        polynomial: &Polynomial<G::Scalar>,
+    ) -> Result<(), usize> {
+        let ypows = powers(y, self.deg_y()); // [y^j]
+
+        for i in 0..max(self.rows, polynomial.size()) {
+            let ai = polynomial.coefficient(i).ok_or(i)?;
+            let mi = self.m.chunks(self.cols).nth(i).ok_or(i)?; // [M_{i,j}]
+            if G::mul_generator(ai) != msm(&ypows, mi) {
+                return Err(i);
+            }
         }
 
-        let deg_x = bytes[0] as usize;
-        let deg_y = bytes[1] as usize;
-        let rows = deg_x + 1;
-        let cols = deg_y + 1;
-        let expected_len = Self::byte_size(rows, cols);
+        Ok(())
+    }
 
-        if bytes.len() != expected_len {
-            return None;
-        }
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate x against
+    /// the verification matrix, as [`Self::verify_x`] does.
+    ///
+    /// Unlike [`Self::verify_x`], this method doesn't short-circuit if the size
+    /// of the polynomial is invalid. Missing coefficients are replaced by zero
+    /// and excess ones are ignored, so the full loop of `deg_y + 1` scalar
+    /// multiplications and multi-scalar multiplications is always executed,
+    /// and the size check is folded into the result. This costs the same as
+    /// a successful [`Self::verify_x`] even for polynomials of invalid size.
+    pub fn verify_x_ct(&self, x: &G::Scalar, polynomial: &Polynomial<G::Scalar>) -> bool {
+        let mut verified = (polynomial.size() as u64).ct_eq(&(self.cols as u64));
+        let xpows = powers(x, self.deg_x()); // [x^i]
 
-        let element_size = Self::element_byte_size();
-        let mut m = Vec::with_capacity(rows);
+        for j in 0..self.cols {
+            let aj = coefficient_or_zero(polynomial, j);
+            let mj: Vec<_> = self.column_iter(j).copied().collect(); // [M_{i,j}]
+            let mut diff = G::mul_generator(&aj); // a_j * G
+            diff -= msm(&xpows, &mj); // \sum_i x^i * M_{i,j} = \sum_i b_{i,j} x^i * G
 
-        for chunks in bytes[2..].chunks(element_size * cols) {
-            let mut mi = Vec::with_capacity(cols);
+            verified &= diff.is_identity();
+        }
 
-            for chunk in chunks.chunks(element_size) {
-                let mut repr: G::Repr = Default::default();
-                repr.as_mut().copy_from_slice(chunk);
+        verified.into()
+    }
 
-                #[allow(clippy::question_mark)]
-                let mij = match G::from_bytes(&repr).into() {
-                    None => return None,
-                    Some(mij) => mij,
-                };
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate y against
+    /// the verification matrix, as [`Self::verify_y`] does.
+    ///
+    /// Unlike [`Self::verify_y`], this method doesn't short-circuit if the size
+    /// of the polynomial is invalid. Missing coefficients are replaced by zero
+    /// and excess ones are ignored, so the full loop of `deg_x + 1` scalar
+    /// multiplications and multi-scalar multiplications is always executed,
+    /// and the size check is folded into the result. This costs the same as
+    /// a successful [`Self::verify_y`] even for polynomials of invalid size.
+    pub fn verify_y_ct(&self, y: &G::Scalar, polynomial: &Polynomial<G::Scalar>) -> bool {
+        let mut verified = (polynomial.size() as u64).ct_eq(&(self.rows as u64));
+        let ypows = powers(y, self.deg_y()); // [y^j]
 
-                mi.push(mij);
-            }
-            m.push(mi);
+        for (i, mi) in self.rows_iter().enumerate() {
+            let ai = coefficient_or_zero(polynomial, i);
+            let mut diff = G::mul_generator(&ai); // a_i * G
+            diff -= msm(&ypows, mi); // \sum_j y^j * M_{i,j} = \sum_j b_{i,j} y^j * G
+
+            verified &= diff.is_identity();
         }
 
-        Some(Self { cols, rows, m })
+        verified.into()
     }
 
-    /// Returns the size of the byte representation of a matrix element.
-    pub fn element_byte_size() -> usize {
-        // Is there a better way?
-        G::Repr::default().as_ref().len()
-    }
+    /// Verifies a full bivariate share of the shareholder at the given
+    /// point, consisting of the row polynomial `B(p,y)` and the column
+    /// polynomial `B(x,p)`.
+    ///
+    /// The share is valid iff the row polynomial verifies as in
+    /// [`Self::verify_x`], the column polynomial verifies as in
+    /// [`Self::verify_y`], and both agree on the diagonal point, i.e.,
+    /// if it holds `row(p) == col(p)`.
+    ///
+    /// All checks are always performed, regardless of the sizes
+    /// or the coefficients of the polynomials.
+    pub fn verify_share(
+        &self,
+        p: &G::Scalar,
+        row: &Polynomial<G::Scalar>,
+        col: &Polynomial<G::Scalar>,
+    ) -> bool {
+        let mut verified = Choice::from(self.verify_x_ct(p, row) as u8);
+        verified &= Choice::from(self.verify_y_ct(p, col) as u8);
+        verified &= row.eval(p).ct_eq(&col.eval(p)); // B(p,p)
 
-    /// Returns the size of the byte representation of the verification matrix.
-    pub fn byte_size(rows: usize, cols: usize) -> usize {
-        2 + rows * cols * Self::element_byte_size()
+        verified.into()
     }
-}
 
-impl<G> From<&BivariatePolynomial<G::Scalar>> for VerificationMatrix<G>
-where
-    G: Group,
-{
-    /// Constructs a new verification matrix from the given bivariate
-    /// polynomial.
-    fn from(bp: &BivariatePolynomial<G::Scalar>) -> Self {
-        let rows = bp.deg_x + 1;
-        let cols = bp.deg_y + 1;
-        let mut m = Vec::new();
-        for bi in bp.b.iter() {
-            let mut mi = Vec::new();
-            for bij in bi.iter() {
-                mi.push(G::generator() * bij) // b_{i,j} * G
+    /// Verifies many polynomials at once, each against its own verification
+    /// matrix, as [`Self::verify_x`] does, where all polynomials result from
+    /// the evaluation of the bivariate polynomials at the same `x` value.
+    ///
+    /// All checks are folded into one using a random linear combination
+    /// of the per-coefficient differences:
+    /// ```text
+    /// \sum_{k,j} r_{k,j} (a_{k,j} * G - \sum_i x^i * M^k_{i,j})
+    ///     = (\sum_{k,j} r_{k,j} a_{k,j}) * G - \sum_{k,i,j} r_{k,j} x^i * M^k_{i,j}
+    /// ```
+    /// so that the whole batch collapses into a single multi-scalar
+    /// multiplication over the elements of all matrices. The challenges
+    /// `r_{k,j}` are sampled from the given RNG after the polynomials
+    /// are fixed.
+    ///
+    /// Polynomials of invalid size make the batch fail, but are processed
+    /// like the others, with missing coefficients replaced by zero and excess
+    /// ones ignored, so the running time doesn't reveal which one is invalid.
+    ///
+    /// Use [`Self::find_invalid_x`] to locate invalid polynomials.
+    pub fn verify_x_batch(
+        items: &[(&VerificationMatrix<G>, &Polynomial<G::Scalar>)],
+        x: &G::Scalar,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> bool {
+        let size: usize = items.iter().map(|(vm, _)| vm.rows * vm.cols).sum();
+        let mut scalars = Vec::with_capacity(size);
+        let mut points = Vec::with_capacity(size);
+        let mut verified = Choice::from(1);
+        let mut a = G::Scalar::ZERO;
+
+        for (vm, polynomial) in items {
+            verified &= (polynomial.size() as u64).ct_eq(&(vm.cols as u64));
+
+            let xpows = powers(x, vm.deg_x()); // [x^i]
+            let rs: Vec<_> = (0..vm.cols).map(|_| G::Scalar::random(&mut *rng)).collect();
+            for (j, r) in rs.iter().enumerate() {
+                a += *r * coefficient_or_zero(polynomial, j); // r_{k,j} a_{k,j}
+            }
+            for (mi, xpow) in vm.rows_iter().zip(xpows) {
+                scalars.extend(rs.iter().map(|r| *r * xpow)); // r_{k,j} x^i
+                points.extend_from_slice(mi);
             }
-            m.push(mi)
         }
 
-        Self { rows, cols, m }
+        // The combined coefficient is secret, so keep it out of the multi-scalar
+        // multiplication, which is not constant time.
+        let mut diff = G::mul_generator(&a);
+        diff -= msm(&scalars, &points); // \sum_{k,i,j} r_{k,j} x^i * M^k_{i,j}
+        verified &= diff.is_identity();
+
+        verified.into()
     }
-}
 
-impl<G> From<BivariatePolynomial<G::Scalar>> for VerificationMatrix<G>
-where
-    G: Group,
-{
-    /// Constructs a new verification matrix from the given bivariate
-    /// polynomial.
-    fn from(bp: BivariatePolynomial<G::Scalar>) -> Self {
-        (&bp).into()
+    /// Returns the indices of the polynomials that don't verify against
+    /// their verification matrices at the given `x` value, in ascending
+    /// order.
+    ///
+    /// This method verifies the polynomials one by one and is meant to be used
+    /// after a failed [`Self::verify_x_batch`] to blame the culprits.
+    pub fn find_invalid_x(
+        items: &[(&VerificationMatrix<G>, &Polynomial<G::Scalar>)],
+        x: &G::Scalar,
+    ) -> Vec<usize> {
+        items
+            .iter()
+            .enumerate()
+            .filter(|(_, (vm, polynomial))| !vm.verify_x_ct(x, polynomial))
+            .map(|(k, _)| k)
+            .collect()
     }
 }
 
-impl<G> Add for VerificationMatrix<G>
+impl<G> VerificationMatrix<G>
 where
-    G: Group,
+    G: Group + GroupEncoding,
 {
-    type Output = VerificationMatrix<G>;
+    /// Returns the byte representation of the verification matrix.
+    ///
+    /// The legacy format is used whenever the degrees fit into a single
+    /// byte, and the extended format otherwise.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let format = Format::for_dimensions(self.rows, self.cols);
+        self.to_bytes_versioned(format)
+            .expect("dimensions should fit the chosen format")
+    }
 
-    #[inline]
-    fn add(self, rhs: Self) -> VerificationMatrix<G> {
-        &self + &rhs
+    /// Returns the byte representation of the first row of the verification
+    /// matrix, i.e., of the verification vector returned by
+    /// [`Self::first_row_commitment`].
+    ///
+    /// The representation is smaller than that of the whole matrix
+    /// by a factor of the number of rows, and can be decoded with
+    /// [`VerificationVector::from_bytes`].
+    ///
+    /// Returns an error if the matrix has more than 256 columns.
+    pub fn to_bytes_first_row(&self) -> Result<Vec<u8>, Error> {
+        self.first_row_commitment().to_bytes()
     }
-}
 
-impl<G> Add<&VerificationMatrix<G>> for VerificationMatrix<G>
-where
-    G: Group,
-{
-    type Output = VerificationMatrix<G>;
+    /// Returns the byte representation of the verification matrix
+    /// in the given format.
+    ///
+    /// Returns an error if the degrees of the matrix don't fit the format.
+    pub fn to_bytes_versioned(&self, format: Format) -> Result<Vec<u8>, Error> {
+        if !format.fits(self.rows, self.cols) {
+            return Err(Error::DimensionsTooLarge);
+        }
 
-    #[inline]
-    fn add(self, rhs: &VerificationMatrix<G>) -> VerificationMatrix<G> {
-        &self + rhs
+        let cap = Self::byte_size_versioned(format, self.rows, self.cols);
+        let mut bytes = Vec::with_capacity(cap);
+        bytes.extend(format.header(PointEncoding::Compressed, self.rows, self.cols));
+        for mij in &self.m {
+            bytes.extend_from_slice(mij.to_bytes().as_ref());
+        }
+
+        Ok(bytes)
     }
-}
 
-impl<G> Add<VerificationMatrix<G>> for &VerificationMatrix<G>
-where
-    G: Group,
-{
-    type Output = VerificationMatrix<G>;
+    /// Writes the byte representation of the verification matrix,
+    /// as produced by [`Self::to_bytes`], to the given writer.
+    ///
+    /// Elements are encoded one at a time, so the whole byte representation
+    /// is never held in memory.
+    pub fn write_to<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let format = Format::for_dimensions(self.rows, self.cols);
+        writer.write_all(&format.header(PointEncoding::Compressed, self.rows, self.cols))?;
+        for mij in &self.m {
+            writer.write_all(mij.to_bytes().as_ref())?;
+        }
 
-    #[inline]
-    fn add(self, rhs: VerificationMatrix<G>) -> VerificationMatrix<G> {
-        self + &rhs
+        Ok(())
     }
-}
 
-impl<G> Add for &VerificationMatrix<G>
-where
-    G: Group,
-{
-    type Output = VerificationMatrix<G>;
+    /// Attempts to read a verification matrix in its byte representation
+    /// from the given reader.
+    ///
+    /// The header is read first, after which elements are decoded one
+    /// at a time directly from the reader, failing on the first invalid
+    /// element. Exactly the bytes of the matrix are consumed, so further
+    /// data may follow in the reader.
+    ///
+    /// Unlike [`Self::try_from_bytes`], the total length isn't known upfront,
+    /// so a legacy header of a 256x256 matrix is told apart from an extended
+    /// header by the byte that follows the marker. This is unambiguous only
+    /// for groups with SEC1 encodings, whose elements never start with
+    /// the format tag. Only compressed elements are supported.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn read_from<R: io::Read>(reader: &mut R) -> Result<Self, DecodeError>
+    where
+        G: Sec1Encoding,
+    {
+        let mut header = [0u8; EXTENDED_HEADER_SIZE];
+        reader.read_exact(&mut header[..LEGACY_HEADER_SIZE])?;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        let rows = max(self.rows, rhs.rows);
-        let cols = max(self.cols, rhs.cols);
-        let mut m = Vec::with_capacity(rows);
+        let mut prefix: &[u8] = &[];
+        let (rows, cols) = if header[..2] != EXTENDED_MARKER {
+            (header[0] as usize + 1, header[1] as usize + 1)
+        } else {
+            reader.read_exact(&mut header[2..3])?;
+            if header[2] == COMPRESSED_TAG {
+                reader.read_exact(&mut header[3..])?;
+                let deg_x = u16::from_le_bytes([header[3], header[4]]) as usize;
+                let deg_y = u16::from_le_bytes([header[5], header[6]]) as usize;
+                (deg_x + 1, deg_y + 1)
+            } else {
+                // The byte belongs to the first element of a legacy matrix.
+                prefix = &header[2..3];
+                (LEGACY_MAX_DIMENSION, LEGACY_MAX_DIMENSION)
+            }
+        };
 
-        for i in 0..rows {
-            let mut mi = Vec::with_capacity(cols);
+        let mut reader = prefix.chain(reader);
+        // The header is untrusted and the reader may end early, so don't
+        // reserve more memory than elements read so far can justify.
+        let len = rows.checked_mul(cols).ok_or(DecodeError::SizeOverflow)?;
+        let mut m = Vec::with_capacity(min(len, MAX_READ_CAPACITY));
 
-            for j in 0..cols {
-                let a = self.m.get(i).and_then(|mi| mi.get(j));
-                let b = rhs.m.get(i).and_then(|mi| mi.get(j));
+        for row in 0..rows {
+            for col in 0..cols {
+                let mut repr: G::Repr = Default::default();
+                reader.read_exact(repr.as_mut())?;
 
-                let s = match (a, b) {
-                    (Some(a), Some(b)) => *a + *b,
-                    (Some(a), None) => *a,
-                    (None, Some(b)) => *b,
-                    (None, None) => G::identity(),
-                };
+                let mij = Option::from(G::from_bytes(&repr))
+                    .ok_or(DecodeError::InvalidElement { row, col })?;
 
-                mi.push(s);
+                m.push(mij);
             }
+        }
+
+        Ok(Self::from_flat(rows, cols, m).expect("dimensions should be valid"))
+    }
+
+    /// Attempts to create a verification matrix from its byte representation.
+    ///
+    /// Both the legacy and the extended format are accepted.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::try_from_bytes(bytes).ok()
+    }
+
+    /// Returns the byte representation of the verification matrix,
+    /// as produced by [`Self::to_bytes`], in lowercase hex.
+    ///
+    /// The header is separated from the elements by a colon,
+    /// e.g., `0203:02aa87...`, so that the dimensions stand out and
+    /// the representations logged by different nodes can be diffed directly.
+    pub fn to_hex(&self) -> String {
+        format!("{:x}", self)
+    }
 
-            m.push(mi);
+    /// Attempts to create a verification matrix from its hex representation,
+    /// as produced by [`Self::to_hex`].
+    ///
+    /// The separator after the header is optional, and both lowercase and
+    /// uppercase digits are accepted.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let (header, elements) = hex.split_once(HEX_SEPARATOR).unwrap_or((hex, ""));
+
+        let mut bytes = decode_hex(header)?;
+        let header_size = bytes.len();
+        bytes.extend(decode_hex(elements)?);
+
+        if hex.contains(HEX_SEPARATOR) {
+            let (format, _, _) = Self::decode_header(&bytes, PointEncoding::Compressed).ok()?;
+            if header_size != format.header_size() {
+                return None;
+            }
         }
 
-        VerificationMatrix { rows, cols, m }
+        Self::from_bytes(&bytes)
     }
-}
 
-impl<G> AddAssign for VerificationMatrix<G>
-where
-    G: Group,
-{
-    #[inline]
-    fn add_assign(&mut self, rhs: VerificationMatrix<G>) {
-        *self += &rhs
+    /// Attempts to create a verification matrix from its byte representation,
+    /// returning an error that describes why decoding failed.
+    ///
+    /// Both the legacy and the extended format are accepted.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let element_size = Self::element_byte_size();
+        Self::decode(bytes, PointEncoding::Compressed, element_size, |chunk| {
+            let mut repr: G::Repr = Default::default();
+            repr.as_mut().copy_from_slice(chunk);
+            G::from_bytes(&repr)
+        })
     }
-}
 
-impl<G> AddAssign<&VerificationMatrix<G>> for VerificationMatrix<G>
-where
-    G: Group,
-{
-    fn add_assign(&mut self, rhs: &VerificationMatrix<G>) {
-        if self.rows < rhs.rows || self.cols < rhs.cols {
-            *self = &*self + rhs;
-            return;
+    /// Attempts to create a verification matrix from its byte representation,
+    /// imposing the additional checks selected by the given options.
+    ///
+    /// Each failed check is reported by a distinct error, so that the reason
+    /// for rejecting a dealing can be reported precisely. Checks are done
+    /// in the order of the fields of the options.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn from_bytes_strict(bytes: &[u8], opts: &DecodeOptions) -> Result<Self, DecodeError> {
+        let vm = Self::try_from_bytes(bytes)?;
+
+        if opts.require_canonical {
+            let element_size = Self::element_byte_size();
+            let header_size = bytes.len() - vm.m.len() * element_size;
+            let chunks = bytes[header_size..].chunks(element_size);
+
+            for ((i, j, mij), chunk) in vm.iter().zip(chunks) {
+                if mij.to_bytes().as_ref() != chunk {
+                    return Err(DecodeError::NonCanonicalElement { row: i, col: j });
+                }
+            }
         }
 
-        for i in 0..rhs.rows {
-            for j in 0..rhs.cols {
-                self.m[i][j] += rhs.m[i][j];
+        for &(i, j) in &opts.forbid_identity {
+            if vm.element(i, j).is_some_and(|mij| mij.is_identity().into()) {
+                return Err(DecodeError::IdentityElement { row: i, col: j });
             }
         }
+
+        let is_identity = |mij: &G| bool::from(mij.is_identity());
+        let leading_row = vm.rows_iter().next_back().unwrap_or_default();
+        if opts.forbid_identity_leading_row && leading_row.iter().all(is_identity) {
+            return Err(DecodeError::IdentityLeadingRow);
+        }
+        if opts.forbid_identity_leading_column && vm.column_iter(vm.deg_y()).all(is_identity) {
+            return Err(DecodeError::IdentityLeadingColumn);
+        }
+
+        Ok(vm)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use group::Group as _;
-    use rand::{rngs::StdRng, SeedableRng};
+    /// Attempts to create a verification matrix from its byte representation,
+    /// additionally checking that every element lies in the prime-order
+    /// subgroup.
+    ///
+    /// Groups with a cofactor may decode elements with a small-order
+    /// component, which are rejected with an error indicating the position
+    /// of the first such element. For prime-order groups the check is a no-op.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn from_bytes_checked(bytes: &[u8]) -> Result<Self, DecodeError>
+    where
+        G: SubgroupCheck,
+    {
+        let vm = Self::try_from_bytes(bytes)?;
 
-    use crate::{poly, vss};
+        for (i, j, mij) in vm.iter() {
+            if !bool::from(mij.is_torsion_free()) {
+                return Err(DecodeError::SmallOrderElement { row: i, col: j });
+            }
+        }
 
-    type PrimeField = p384::Scalar;
-    type Group = p384::ProjectivePoint;
-    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
-    type VerificationMatrix = vss::VerificationMatrix<Group>;
+        Ok(vm)
+    }
 
-    fn scalar(value: i64) -> PrimeField {
-        scalars(&vec![value])[0]
+    /// Decodes a verification matrix from its byte representation, where
+    /// elements of the given encoding and size are decoded by the given
+    /// function.
+    fn decode<F>(
+        bytes: &[u8],
+        encoding: PointEncoding,
+        element_size: usize,
+        decode_element: F,
+    ) -> Result<Self, DecodeError>
+    where
+        F: Fn(&[u8]) -> CtOption<G>,
+    {
+        let (format, rows, cols) = Self::decode_header(bytes, encoding)?;
+        let expected_len = checked_byte_size(format.header_size(), element_size, rows, cols)
+            .ok_or(DecodeError::SizeOverflow)?;
+
+        if bytes.len() != expected_len {
+            return Err(DecodeError::LengthMismatch {
+                expected: expected_len,
+                got: bytes.len(),
+            });
+        }
+
+        let mut m = Vec::with_capacity(rows * cols);
+
+        for (k, chunk) in bytes[format.header_size()..]
+            .chunks(element_size)
+            .enumerate()
+        {
+            let mij = Option::from(decode_element(chunk)).ok_or(DecodeError::InvalidElement {
+                row: k / cols,
+                col: k % cols,
+            })?;
+
+            m.push(mij);
+        }
+
+        Ok(Self::from_flat(rows, cols, m).expect("dimensions should be valid"))
     }
 
-    fn scalars(values: &[i64]) -> Vec<PrimeField> {
-        values
-            .iter()
-            .map(|&w| match w.is_negative() {
-                false => PrimeField::from_u64(w as u64),
-                true => PrimeField::from_u64(-w as u64).neg(),
-            })
-            .collect()
+    /// Attempts to create a verification matrix from its byte representation,
+    /// rejecting matrices whose degrees exceed the given bounds, and returning
+    /// an error that describes why decoding failed.
+    ///
+    /// The degrees are checked right after the header is decoded, before
+    /// any memory is allocated for the elements, so that untrusted input
+    /// cannot force the allocation of a large matrix.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn try_from_bytes_bounded(
+        bytes: &[u8],
+        max_deg_x: usize,
+        max_deg_y: usize,
+    ) -> Result<Self, DecodeError> {
+        let (_, rows, cols) = Self::decode_header(bytes, PointEncoding::Compressed)?;
+        if rows - 1 > max_deg_x || cols - 1 > max_deg_y {
+            return Err(DecodeError::DegreesExceeded {
+                max: (max_deg_x, max_deg_y),
+                got: (rows - 1, cols - 1),
+            });
+        }
+
+        Self::try_from_bytes(bytes)
     }
 
-    #[test]
-    fn test_from() {
-        // Two non-zero coefficients (fast).
-        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
-        let mut bp = BivariatePolynomial::zero(2, 3);
-        assert!(bp.set_coefficient(0, 0, PrimeField::ONE));
-        assert!(bp.set_coefficient(1, 2, PrimeField::ONE.double()));
+    /// Attempts to create a verification matrix of the given dimensions
+    /// from its byte representation.
+    ///
+    /// Unlike [`Self::from_bytes`], the size of the matrix is determined
+    /// by the given dimensions rather than by the embedded header, which must
+    /// nevertheless match them and use the format chosen by [`Self::to_bytes`].
+    /// All elements are decoded and the header is checked without
+    /// short-circuiting, so that the time taken doesn't depend on where
+    /// the byte representation is invalid. Only a length mismatch is rejected
+    /// early.
+    pub fn from_bytes_fixed(bytes: &[u8], rows: usize, cols: usize) -> Option<Self>
+    where
+        G: ConditionallySelectable,
+    {
+        if rows == 0 || cols == 0 || rows > MAX_DIMENSION || cols > MAX_DIMENSION {
+            return None;
+        }
 
-        let vm = VerificationMatrix::from(&bp);
-        assert_eq!(vm.m.len(), 3);
-        for (i, mi) in vm.m.iter().enumerate() {
-            assert_eq!(mi.len(), 4);
-            for (j, mij) in mi.iter().enumerate() {
-                match (i, j) {
-                    (0, 0) => assert_eq!(mij, &Group::GENERATOR),
-                    (1, 2) => assert_eq!(mij, &Group::GENERATOR.double()),
-                    _ => assert_eq!(mij, &Group::IDENTITY),
-                }
-            }
+        let format = Format::for_dimensions(rows, cols);
+        if Some(bytes.len()) != Self::checked_byte_size_versioned(format, rows, cols) {
+            return None;
+        }
+
+        let header = format.header(PointEncoding::Compressed, rows, cols);
+        let mut valid = bytes[..header.len()].ct_eq(&header);
+
+        let element_size = Self::element_byte_size();
+        let mut m = Vec::with_capacity(rows * cols);
+
+        for chunk in bytes[header.len()..].chunks(element_size) {
+            let mut repr: G::Repr = Default::default();
+            repr.as_mut().copy_from_slice(chunk);
+
+            let mij = G::from_bytes(&repr);
+            valid &= mij.is_some();
+            m.push(mij.unwrap_or(G::identity()));
         }
 
-        // Random bivariate polynomial (slow).
-        let bp = BivariatePolynomial::random(5, 10, &mut rng);
-        let _ = VerificationMatrix::from(&bp);
+        let vm = Self::from_flat(rows, cols, m).expect("dimensions should be valid");
+        CtOption::new(vm, valid).into()
+    }
+
+    /// Attempts to create a partial verification matrix from its byte
+    /// representation, tolerating elements that fail to decode.
+    ///
+    /// Returns the partial matrix together with the positions `(i, j)`
+    /// of the missing elements in row-major order, or `None` if the header
+    /// is invalid or the length doesn't match the dimensions.
+    #[allow(clippy::type_complexity)]
+    pub fn from_bytes_lossy(bytes: &[u8]) -> Option<(PartialMatrix<G>, Vec<(usize, usize)>)> {
+        let (format, rows, cols) = Self::decode_header(bytes, PointEncoding::Compressed).ok()?;
+        let expected_len = Self::checked_byte_size_versioned(format, rows, cols)?;
+
+        if bytes.len() != expected_len {
+            return None;
+        }
+
+        let element_size = Self::element_byte_size();
+        let mut m = Vec::with_capacity(rows * cols);
+        let mut missing = Vec::with_capacity(rows * cols);
+
+        for chunk in bytes[format.header_size()..].chunks(element_size) {
+            let mut repr: G::Repr = Default::default();
+            repr.as_mut().copy_from_slice(chunk);
+
+            let mij: Option<G> = G::from_bytes(&repr).into();
+            missing.push(mij.is_none());
+            m.push(mij.unwrap_or(G::identity()));
+        }
+
+        let pm = PartialMatrix::new(Self::from_flat(rows, cols, m).ok()?, missing);
+        let positions = pm.missing();
+
+        Some((pm, positions))
+    }
+
+    /// Decodes the header of the byte representation of a verification
+    /// matrix with elements of the given encoding, returning its format
+    /// and dimensions.
+    ///
+    /// A legacy header of a 256x256 matrix starts with the same bytes
+    /// as an extended header, so the two are told apart by the length
+    /// of the input. The lengths of the two formats differ by the difference
+    /// of the header sizes modulo the element size, so an extended encoding
+    /// can have the length of such a matrix only if the element size divides
+    /// that difference. Input that could be either is rejected.
+    pub(crate) fn decode_header(
+        bytes: &[u8],
+        encoding: PointEncoding,
+    ) -> Result<(Format, usize, usize), DecodeError> {
+        if bytes.len() < LEGACY_HEADER_SIZE {
+            return Err(DecodeError::TooShort);
+        }
+
+        let legacy_max_len =
+            Self::byte_size_versioned(Format::Legacy, LEGACY_MAX_DIMENSION, LEGACY_MAX_DIMENSION);
+
+        let is_marker = bytes[..2] == EXTENDED_MARKER;
+        if is_marker && bytes.len() == legacy_max_len {
+            let size_diff = EXTENDED_HEADER_SIZE - LEGACY_HEADER_SIZE;
+            if size_diff % Self::element_byte_size() == 0 {
+                return Err(DecodeError::UnsupportedFormat(bytes[2]));
+            }
+        }
+
+        if !is_marker || bytes.len() == legacy_max_len {
+            if encoding != PointEncoding::Compressed {
+                return Err(DecodeError::UnexpectedEncoding);
+            }
+            let rows = bytes[0] as usize + 1;
+            let cols = bytes[1] as usize + 1;
+            return Ok((Format::Legacy, rows, cols));
+        }
+
+        if bytes.len() < EXTENDED_HEADER_SIZE {
+            return Err(DecodeError::TooShort);
+        }
+
+        let found = match bytes[2] {
+            COMPRESSED_TAG => PointEncoding::Compressed,
+            UNCOMPRESSED_TAG => PointEncoding::Uncompressed,
+            tag => return Err(DecodeError::UnsupportedFormat(tag)),
+        };
+        if found != encoding {
+            return Err(DecodeError::UnexpectedEncoding);
+        }
+
+        let deg_x = u16::from_le_bytes([bytes[3], bytes[4]]) as usize;
+        let deg_y = u16::from_le_bytes([bytes[5], bytes[6]]) as usize;
+
+        Ok((Format::Extended, deg_x + 1, deg_y + 1))
+    }
+
+    /// Returns a digest of the canonical encoding of the verification matrix.
+    ///
+    /// The encoding is domain-separated and independent of the byte
+    /// representation format:
+    /// ```text
+    ///     context || element_size || G || rows || cols || M_{0,0} || ... || M_{deg_x,deg_y}
+    /// ```
+    /// where the element size is a 16-bit and the dimensions are 32-bit
+    /// little-endian integers, and group elements, including the generator
+    /// `G` which identifies the group, are compressed.
+    pub fn digest<D: Digest>(&self) -> Output<D> {
+        let mut hasher = D::new();
+        hasher.update(DIGEST_CONTEXT);
+        hasher.update((Self::element_byte_size() as u16).to_le_bytes());
+        hasher.update(G::generator().to_bytes());
+        hasher.update((self.rows as u32).to_le_bytes());
+        hasher.update((self.cols as u32).to_le_bytes());
+        for mij in &self.m {
+            hasher.update(mij.to_bytes());
+        }
+
+        hasher.finalize()
+    }
+
+    /// Returns the size of the byte representation of a matrix element.
+    pub fn element_byte_size() -> usize {
+        // Is there a better way?
+        G::Repr::default().as_ref().len()
+    }
+
+    /// Returns the size of the byte representation of the verification matrix,
+    /// as produced by [`Self::to_bytes`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size overflows `usize`.
+    pub fn byte_size(rows: usize, cols: usize) -> usize {
+        Self::byte_size_versioned(Format::for_dimensions(rows, cols), rows, cols)
+    }
+
+    /// Returns the size of the byte representation of the verification matrix
+    /// in the given format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size overflows `usize`.
+    pub fn byte_size_versioned(format: Format, rows: usize, cols: usize) -> usize {
+        Self::checked_byte_size_versioned(format, rows, cols)
+            .expect("byte size should not overflow")
+    }
+
+    /// Returns the size of the byte representation of the verification matrix
+    /// in the given format, or `None` if it overflows `usize`.
+    ///
+    /// Dimensions decoded from untrusted headers must be checked this way,
+    /// as the size of the largest matrices overflows on 32-bit targets.
+    pub(crate) fn checked_byte_size_versioned(
+        format: Format,
+        rows: usize,
+        cols: usize,
+    ) -> Option<usize> {
+        checked_byte_size(format.header_size(), Self::element_byte_size(), rows, cols)
+    }
+}
+
+impl<G> VerificationMatrix<G>
+where
+    G: Group + UncompressedEncoding,
+{
+    /// Returns the byte representation of the verification matrix
+    /// with elements in the given encoding.
+    ///
+    /// Compressed elements are encoded as by [`Self::to_bytes`], while
+    /// uncompressed elements always use the extended format.
+    pub fn to_bytes_with(&self, encoding: PointEncoding) -> Vec<u8> {
+        if encoding == PointEncoding::Compressed {
+            return self.to_bytes();
+        }
+
+        let cap = Self::byte_size_with(encoding, self.rows, self.cols);
+        let mut bytes = Vec::with_capacity(cap);
+        bytes.extend(Format::Extended.header(encoding, self.rows, self.cols));
+        for mij in &self.m {
+            bytes.extend_from_slice(mij.to_uncompressed().as_ref());
+        }
+
+        bytes
+    }
+
+    /// Attempts to create a verification matrix from its byte representation
+    /// with elements in the given encoding.
+    ///
+    /// The encoding must match the one recorded in the header.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn from_bytes_with(bytes: &[u8], encoding: PointEncoding) -> Option<Self> {
+        if encoding == PointEncoding::Compressed {
+            return Self::from_bytes(bytes);
+        }
+
+        let element_size = Self::uncompressed_element_byte_size();
+        Self::decode(bytes, encoding, element_size, |chunk| {
+            let mut repr: G::Uncompressed = Default::default();
+            repr.as_mut().copy_from_slice(chunk);
+            G::from_uncompressed(&repr)
+        })
+        .ok()
+    }
+
+    /// Returns the size of the byte representation of the verification matrix
+    /// with elements in the given encoding, as produced
+    /// by [`Self::to_bytes_with`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size overflows `usize`.
+    pub fn byte_size_with(encoding: PointEncoding, rows: usize, cols: usize) -> usize {
+        match encoding {
+            PointEncoding::Compressed => Self::byte_size(rows, cols),
+            PointEncoding::Uncompressed => checked_byte_size(
+                EXTENDED_HEADER_SIZE,
+                Self::uncompressed_element_byte_size(),
+                rows,
+                cols,
+            )
+            .expect("byte size should not overflow"),
+        }
+    }
+
+    /// Returns the size of the uncompressed encoding of a matrix element.
+    fn uncompressed_element_byte_size() -> usize {
+        G::Uncompressed::default().as_ref().len()
+    }
+}
+
+impl<G> fmt::LowerHex for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Formats the byte representation of the verification matrix
+    /// as described in [`VerificationMatrix::to_hex`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = Format::for_dimensions(self.rows, self.cols);
+        for b in format.header(PointEncoding::Compressed, self.rows, self.cols) {
+            write!(f, "{:02x}", b)?;
+        }
+        write!(f, "{}", HEX_SEPARATOR)?;
+        for mij in &self.m {
+            for b in mij.to_bytes().as_ref() {
+                write!(f, "{:02x}", b)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes the given hex string, returning `None` if it has an odd length
+/// or contains characters other than hex digits.
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let hi = (pair[0] as char).to_digit(16)?;
+            let lo = (pair[1] as char).to_digit(16)?;
+            Some((hi << 4 | lo) as u8)
+        })
+        .collect()
+}
+
+/// Returns the size of the byte representation of a verification matrix
+/// with the given header and element sizes, or `None` if it overflows `usize`.
+fn checked_byte_size(
+    header_size: usize,
+    element_size: usize,
+    rows: usize,
+    cols: usize,
+) -> Option<usize> {
+    rows.checked_mul(cols)?
+        .checked_mul(element_size)?
+        .checked_add(header_size)
+}
+
+/// Returns the `k`-th coefficient of the polynomial, or zero if it doesn't
+/// exist, without branching on the size of the polynomial.
+fn coefficient_or_zero<F: PrimeField>(polynomial: &Polynomial<F>, k: usize) -> F {
+    let size = polynomial.a.len(); // Polynomials are never empty.
+    let exists = (k as u64).ct_lt(&(size as u64));
+    let ak = polynomial.a[min(k, size - 1)];
+
+    F::conditional_select(&F::ZERO, &ak, exists)
+}
+
+impl<G> Hash for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rows.hash(state);
+        self.cols.hash(state);
+        for mij in &self.m {
+            mij.to_bytes().as_ref().hash(state);
+        }
+    }
+}
+
+impl<G> ConstantTimeEq for VerificationMatrix<G>
+where
+    G: Group + ConstantTimeEq,
+{
+    /// Compares the verification matrices in constant time.
+    ///
+    /// The dimensions are public and compared first, after which all
+    /// elements are compared without short-circuiting, so that the time
+    /// taken doesn't reveal which element differs. Use the derived
+    /// [`PartialEq`] when timing is not a concern.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Choice::from(0);
+        }
+
+        let mut eq = Choice::from(1);
+        for (a, b) in self.m.iter().zip(&other.m) {
+            eq &= a.ct_eq(b);
+        }
+
+        eq
+    }
+}
+
+impl<G> VerificationMatrix<G>
+where
+    G: Group,
+{
+    /// Returns a random bivariate polynomial of the given degrees together
+    /// with its verification matrix.
+    ///
+    /// In debug builds, the matrix is checked against the polynomial
+    /// at a random point.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn random(
+        deg_x: u8,
+        deg_y: u8,
+        rng: &mut impl RngCore,
+    ) -> (BivariatePolynomial<G::Scalar>, Self) {
+        let bp = BivariatePolynomial::random(deg_x, deg_y, rng);
+        let vm = Self::from(&bp);
+        vm.debug_assert_consistent(&bp, rng);
+
+        (bp, vm)
+    }
+
+    /// Returns a random zero-hole bivariate polynomial of the given degrees
+    /// together with its verification matrix, whose element `M_{0,0}`
+    /// is the identity.
+    ///
+    /// In debug builds, the matrix is checked against the polynomial
+    /// at a random point.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn random_zero_hole(
+        deg_x: u8,
+        deg_y: u8,
+        rng: &mut impl RngCore,
+    ) -> (BivariatePolynomial<G::Scalar>, Self) {
+        let bp = BivariatePolynomial::random_with_zero_hole(deg_x, deg_y, rng);
+        let vm = Self::from(&bp);
+        debug_assert!(vm.is_zero_hole());
+        vm.debug_assert_consistent(&bp, rng);
+
+        (bp, vm)
+    }
+
+    /// Asserts in debug builds that the verification matrix verifies
+    /// an evaluation of the given polynomial at a random point.
+    ///
+    /// The point is sampled in all builds, so that the random number
+    /// generator is left in the same state.
+    #[cfg(any(test, feature = "test-utils"))]
+    fn debug_assert_consistent(&self, bp: &BivariatePolynomial<G::Scalar>, rng: &mut impl RngCore) {
+        let x = G::Scalar::random(&mut *rng);
+        let y = G::Scalar::random(&mut *rng);
+        debug_assert!(self.verify(&x, &y, &bp.eval(&x, &y)));
+    }
+
+    /// Constructs a new verification matrix from the given bivariate
+    /// polynomial, where the elements `b_{i,j} * G` are computed
+    /// by the given function.
+    ///
+    /// With the `rayon` feature enabled, the elements are computed
+    /// in parallel.
+    fn from_polynomial_with<F>(bp: &BivariatePolynomial<G::Scalar>, mul: F) -> Self
+    where
+        F: Fn(&G::Scalar) -> G + Sync,
+    {
+        #[cfg(not(feature = "rayon"))]
+        let m = {
+            let mut m = Vec::with_capacity((bp.deg_x + 1) * (bp.deg_y + 1));
+            for bi in bp.b.iter() {
+                for bij in bi.iter() {
+                    m.push(mul(bij)) // b_{i,j} * G
+                }
+            }
+            m
+        };
+
+        #[cfg(feature = "rayon")]
+        let m =
+            bp.b.par_iter()
+                .flat_map(|bi| bi.par_iter().map(&mul)) // b_{i,j} * G
+                .collect();
+
+        Self::from_flat(bp.deg_x + 1, bp.deg_y + 1, m).expect("verification matrix should be valid")
+    }
+}
+
+impl<G> VerificationMatrix<G>
+where
+    G: Group + ConditionallySelectable,
+{
+    /// Constructs a new verification matrix from the given bivariate
+    /// polynomial, multiplying the generator using the given precomputed
+    /// table.
+    ///
+    /// Sharing one table saves its precomputation when many verification
+    /// matrices are constructed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degrees of the polynomial exceed the limits
+    /// of the serialization format.
+    pub fn from_polynomial_with_table(
+        bp: &BivariatePolynomial<G::Scalar>,
+        table: &GeneratorTable<G>,
+    ) -> Self {
+        Self::from_polynomial_with(bp, |bij| table.mul(bij))
+    }
+
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate x against
+    /// the verification matrix, as [`Self::verify_x`] does, multiplying
+    /// the generator using the given precomputed table.
+    ///
+    /// The table must be built for the generator, see [`GeneratorTable::new`].
+    pub fn verify_x_with_table(
+        &self,
+        x: &G::Scalar,
+        polynomial: &Polynomial<G::Scalar>,
+        table: &GeneratorTable<G>,
+    ) -> bool {
+        self.verify_x_with(x, polynomial, |aj| table.mul(aj))
+    }
+
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate y against
+    /// the verification matrix, as [`Self::verify_y`] does, multiplying
+    /// the generator using the given precomputed table.
+    ///
+    /// The table must be built for the generator, see [`GeneratorTable::new`].
+    pub fn verify_y_with_table(
+        &self,
+        y: &G::Scalar,
+        polynomial: &Polynomial<G::Scalar>,
+        table: &GeneratorTable<G>,
+    ) -> bool {
+        self.verify_y_with(y, polynomial, |ai| table.mul(ai))
+    }
+
+    /// Constructs a new verification matrix from the given bivariate
+    /// polynomial, committing to its coefficients against the given base
+    /// instead of the generator, i.e., `M_{i,j} = b_{i,j} * H`.
+    ///
+    /// This allows deployments to use an application-specific base point,
+    /// e.g., one obtained by hashing to the curve. Such matrices must be
+    /// verified with [`Self::verify_with_base`] using the same base.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degrees of the polynomial exceed the limits
+    /// of the serialization format.
+    pub fn from_with_base(bp: &BivariatePolynomial<G::Scalar>, base: &G) -> Self {
+        if (bp.deg_x + 1) * (bp.deg_y + 1) < TABLE_THRESHOLD {
+            return Self::from_polynomial_with(bp, |bij| *base * bij);
+        }
+
+        Self::from_polynomial_with_table(bp, &GeneratorTable::with_base(*base))
+    }
+}
+
+impl<G> From<&BivariatePolynomial<G::Scalar>> for VerificationMatrix<G>
+where
+    G: Group,
+{
+    /// Constructs a new verification matrix from the given bivariate
+    /// polynomial.
+    ///
+    /// With the `rayon` feature enabled, the elements are computed
+    /// in parallel. Supported suites multiply the generator using a cached
+    /// [`GeneratorTable`], other groups fall back to the naive multiplication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degrees of the polynomial exceed the limits
+    /// of the serialization format.
+    fn from(bp: &BivariatePolynomial<G::Scalar>) -> Self {
+        Self::from_polynomial_with(bp, G::mul_generator)
+    }
+}
+
+impl<G> From<BivariatePolynomial<G::Scalar>> for VerificationMatrix<G>
+where
+    G: Group,
+{
+    /// Constructs a new verification matrix from the given bivariate
+    /// polynomial.
+    fn from(bp: BivariatePolynomial<G::Scalar>) -> Self {
+        (&bp).into()
+    }
+}
+
+impl<G> Add for VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    #[inline]
+    fn add(self, rhs: Self) -> VerificationMatrix<G> {
+        &self + &rhs
+    }
+}
+
+impl<G> Add<&VerificationMatrix<G>> for VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    #[inline]
+    fn add(self, rhs: &VerificationMatrix<G>) -> VerificationMatrix<G> {
+        &self + rhs
+    }
+}
+
+impl<G> Add<VerificationMatrix<G>> for &VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    #[inline]
+    fn add(self, rhs: VerificationMatrix<G>) -> VerificationMatrix<G> {
+        self + &rhs
+    }
+}
+
+impl<G> Add for &VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    /// Adds the verification matrices, padding the smaller one with identity
+    /// elements, so that the result is the verification matrix of the sum
+    /// of the bivariate polynomials, regardless of their degrees.
+    ///
+    /// Use [`VerificationMatrix::checked_add`] to reject matrices
+    /// of different dimensions.
+    fn add(self, rhs: Self) -> Self::Output {
+        let rows = max(self.rows, rhs.rows);
+        let cols = max(self.cols, rhs.cols);
+        let mut m = self.padded(rows, cols);
+
+        for (ai, bi) in m.chunks_mut(cols).zip(rhs.rows_iter()) {
+            add_assign_slices(&mut ai[..bi.len()], bi);
+        }
+
+        VerificationMatrix { rows, cols, m }
+    }
+}
+
+impl<G> AddAssign for VerificationMatrix<G>
+where
+    G: Group,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: VerificationMatrix<G>) {
+        *self += &rhs
+    }
+}
+
+impl<G> AddAssign<&VerificationMatrix<G>> for VerificationMatrix<G>
+where
+    G: Group,
+{
+    /// Adds the verification matrix in place, padding the smaller one
+    /// with identity elements, as [`Add`] does.
+    ///
+    /// Use [`VerificationMatrix::checked_add_assign`] to reject matrices
+    /// of different dimensions.
+    fn add_assign(&mut self, rhs: &VerificationMatrix<G>) {
+        if self.rows < rhs.rows || self.cols < rhs.cols {
+            *self = &*self + rhs;
+            return;
+        }
+
+        for (ai, bi) in self.m.chunks_mut(self.cols).zip(rhs.rows_iter()) {
+            add_assign_slices(&mut ai[..bi.len()], bi);
+        }
+    }
+}
+
+impl<G> Sum for VerificationMatrix<G>
+where
+    G: Group,
+{
+    /// Sums the verification matrices, treating missing elements
+    /// as the identity.
+    ///
+    /// The sum of an empty iterator is the 1x1 identity matrix, i.e.,
+    /// the verification matrix of the zero polynomial.
+    fn sum<I: Iterator<Item = VerificationMatrix<G>>>(iter: I) -> VerificationMatrix<G> {
+        let matrices: Vec<_> = iter.collect();
+        sum_matrices(matrices.iter())
+    }
+}
+
+impl<'a, G> Sum<&'a VerificationMatrix<G>> for VerificationMatrix<G>
+where
+    G: Group,
+{
+    /// Sums the verification matrices, treating missing elements
+    /// as the identity.
+    ///
+    /// The sum of an empty iterator is the 1x1 identity matrix, i.e.,
+    /// the verification matrix of the zero polynomial.
+    fn sum<I: Iterator<Item = &'a VerificationMatrix<G>>>(iter: I) -> VerificationMatrix<G> {
+        let matrices: Vec<_> = iter.collect();
+        sum_matrices(matrices.into_iter())
+    }
+}
+
+/// Sums the given verification matrices into a single accumulator
+/// allocated upfront with the largest dimensions among them.
+fn sum_matrices<'a, G, I>(matrices: I) -> VerificationMatrix<G>
+where
+    G: Group,
+    I: Iterator<Item = &'a VerificationMatrix<G>> + Clone,
+{
+    let rows = matrices.clone().map(|vm| vm.rows).fold(1, max);
+    let cols = matrices.clone().map(|vm| vm.cols).fold(1, max);
+    let mut m = vec![G::identity(); rows * cols];
+
+    for vm in matrices {
+        for (ai, bi) in m.chunks_mut(cols).zip(vm.rows_iter()) {
+            add_assign_slices(&mut ai[..bi.len()], bi);
+        }
+    }
+
+    VerificationMatrix { rows, cols, m }
+}
+
+impl<G> Neg for VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    fn neg(mut self) -> VerificationMatrix<G> {
+        for mij in self.m.iter_mut() {
+            *mij = -*mij;
+        }
+
+        self
+    }
+}
+
+impl<G> Neg for &VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    fn neg(self) -> VerificationMatrix<G> {
+        -self.clone()
+    }
+}
+
+impl<G> Sub for VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> VerificationMatrix<G> {
+        &self - &rhs
+    }
+}
+
+impl<G> Sub<&VerificationMatrix<G>> for VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    #[inline]
+    fn sub(self, rhs: &VerificationMatrix<G>) -> VerificationMatrix<G> {
+        &self - rhs
+    }
+}
+
+impl<G> Sub<VerificationMatrix<G>> for &VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    #[inline]
+    fn sub(self, rhs: VerificationMatrix<G>) -> VerificationMatrix<G> {
+        self - &rhs
+    }
+}
+
+impl<G> Sub for &VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let rows = max(self.rows, rhs.rows);
+        let cols = max(self.cols, rhs.cols);
+        let mut m = self.padded(rows, cols);
+
+        for (ai, bi) in m.chunks_mut(cols).zip(rhs.rows_iter()) {
+            sub_assign_slices(&mut ai[..bi.len()], bi);
+        }
+
+        VerificationMatrix { rows, cols, m }
+    }
+}
+
+impl<G> SubAssign for VerificationMatrix<G>
+where
+    G: Group,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: VerificationMatrix<G>) {
+        *self -= &rhs
+    }
+}
+
+impl<G> SubAssign<&VerificationMatrix<G>> for VerificationMatrix<G>
+where
+    G: Group,
+{
+    fn sub_assign(&mut self, rhs: &VerificationMatrix<G>) {
+        if self.rows < rhs.rows || self.cols < rhs.cols {
+            *self = &*self - rhs;
+            return;
+        }
+
+        for (ai, bi) in self.m.chunks_mut(self.cols).zip(rhs.rows_iter()) {
+            sub_assign_slices(&mut ai[..bi.len()], bi);
+        }
+    }
+}
+
+// Scalars are accepted both by value and by reference through `Borrow`,
+// as separate impls for `G::Scalar` and `&G::Scalar` would conflict.
+impl<G, S> Mul<S> for VerificationMatrix<G>
+where
+    G: Group,
+    S: Borrow<G::Scalar>,
+{
+    type Output = VerificationMatrix<G>;
+
+    #[inline]
+    fn mul(mut self, scalar: S) -> VerificationMatrix<G> {
+        self *= scalar;
+        self
+    }
+}
+
+impl<G, S> Mul<S> for &VerificationMatrix<G>
+where
+    G: Group,
+    S: Borrow<G::Scalar>,
+{
+    type Output = VerificationMatrix<G>;
+
+    fn mul(self, scalar: S) -> VerificationMatrix<G> {
+        self.clone() * scalar
+    }
+}
+
+impl<G, S> MulAssign<S> for VerificationMatrix<G>
+where
+    G: Group,
+    S: Borrow<G::Scalar>,
+{
+    fn mul_assign(&mut self, scalar: S) {
+        scale_slice(&mut self.m, scalar.borrow());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use std::{cmp::max, collections::HashSet, convert::TryFrom};
+
+    use group::{ff::Field, Group as _, GroupEncoding};
+    use rand::{rngs::StdRng, SeedableRng};
+    use sha3::Sha3_256;
+    use subtle::ConstantTimeEq;
+
+    use crate::{
+        poly,
+        vss::{
+            self, DecodeError, DecodeOptions, Error, Format, PointEncoding, VerificationContext,
+        },
+    };
+
+    use super::checked_byte_size;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type GeneratorTable = vss::GeneratorTable<Group>;
+    type Polynomial = poly::Polynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+    type VerificationVector = vss::VerificationVector<Group>;
+
+    fn scalar(value: i64) -> PrimeField {
+        scalars(&vec![value])[0]
+    }
+
+    fn scalars(values: &[i64]) -> Vec<PrimeField> {
+        values
+            .iter()
+            .map(|&w| match w.is_negative() {
+                false => PrimeField::from_u64(w as u64),
+                true => PrimeField::from_u64(-w as u64).neg(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_from() {
+        // Two non-zero coefficients (fast).
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut bp = BivariatePolynomial::zero(2, 3);
+        assert!(bp.set_coefficient(0, 0, PrimeField::ONE));
+        assert!(bp.set_coefficient(1, 2, PrimeField::ONE.double()));
+
+        let vm = VerificationMatrix::from(&bp);
+        assert_eq!(vm.dimensions(), (3, 4));
+        for (i, mi) in vm.rows_iter().enumerate() {
+            assert_eq!(mi.len(), 4);
+            for (j, mij) in mi.iter().enumerate() {
+                match (i, j) {
+                    (0, 0) => assert_eq!(mij, &Group::GENERATOR),
+                    (1, 2) => assert_eq!(mij, &Group::GENERATOR.double()),
+                    _ => assert_eq!(mij, &Group::IDENTITY),
+                }
+            }
+        }
+
+        // Random bivariate polynomial (slow).
+        let bp = BivariatePolynomial::random(5, 10, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        assert_eq!(vm.dimensions(), (6, 11));
+        for (i, j, mij) in vm.iter() {
+            assert_eq!(mij, &(Group::GENERATOR * bp.b[i][j]));
+        }
+
+        // Shared generator table.
+        let table = GeneratorTable::new();
+        let restored = VerificationMatrix::from_polynomial_with_table(&bp, &table);
+        assert_eq!(restored, vm);
+
+        // The cached table matches the naive path bit-for-bit.
+        let naive = VerificationMatrix::from_polynomial_with(&bp, |bij| Group::generator() * bij);
+        assert_eq!(naive.to_bytes(), vm.to_bytes());
+
+        let bp = BivariatePolynomial::random(0, 1, &mut rng);
+        let vm = VerificationMatrix::from_polynomial_with_table(&bp, &table);
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+    }
+
+    #[test]
+    fn test_new() {
+        let g = Group::GENERATOR;
+        let vm = VerificationMatrix::new(vec![vec![g, g, g], vec![g, g, g]])
+            .expect("matrix should be valid");
+        assert_eq!(vm.dimensions(), (2, 3));
+
+        let vm = VerificationMatrix::new(vec![vec![g; 256]; 256]).expect("matrix should be valid");
+        assert_eq!(vm.dimensions(), (256, 256));
+
+        let test_cases = vec![
+            (vec![], Error::EmptyMatrix),
+            (vec![vec![]], Error::EmptyMatrix),
+            (vec![vec![], vec![]], Error::EmptyMatrix),
+            (vec![vec![g, g], vec![g]], Error::RaggedMatrix),
+            (vec![vec![g], vec![]], Error::RaggedMatrix),
+            (vec![vec![g]; 65537], Error::DimensionsTooLarge),
+            (vec![vec![g; 65537]], Error::DimensionsTooLarge),
+        ];
+
+        for (m, err) in test_cases {
+            let res = VerificationMatrix::new(m);
+            assert!(res.is_err());
+            assert_eq!(res.unwrap_err().to_string(), err.to_string());
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        // Empty matrices cannot be constructed.
+        assert!(VerificationMatrix::new(vec![]).is_err());
+        assert!(VerificationMatrix::new(vec![vec![]]).is_err());
+        assert!(VerificationMatrix::from_bytes(&[]).is_none());
+        assert!(VerificationMatrix::from_bytes_lossy(&[]).is_none());
+        let vm = VerificationMatrix::from(&BivariatePolynomial::with_coefficients(vec![]));
+        assert_eq!(vm.dimensions(), (1, 1));
+
+        // The smallest matrix supports all methods.
+        let x = scalar(2);
+        let y = scalar(3);
+        let zero = scalar(0);
+        let p = Polynomial::with_coefficients(vec![zero]);
+
+        assert_eq!(vm.element(0, 0), Some(&Group::IDENTITY));
+        assert_eq!(vm.element(0, 1), None);
+        assert!(vm.is_zero_hole());
+        assert!(vm.verify(&x, &y, &zero));
+        assert!(!vm.verify(&x, &y, &scalar(1)));
+        assert!(vm.verify_x(&x, &p));
+        assert!(vm.verify_y(&y, &p));
+        assert!(vm.verification_vector_for_x(&y).is_from(&p));
+        assert!(vm.verification_vector_for_y(&x).is_from(&p));
+        assert!(vm.prepare_x(&x).verify_at_y(&y, &zero));
+        assert_eq!(vm.transpose(), vm);
+        assert_eq!(&vm + &vm, vm);
+        assert_eq!(&vm - &vm, vm);
+        assert_eq!(&vm * x, vm);
+        assert_eq!(VerificationMatrix::from_bytes(&vm.to_bytes()), Some(vm));
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(5, 10, &mut rng);
+
+        let vm = VerificationMatrix::from(&bp);
+        assert_eq!((6, 11), vm.dimensions());
+        assert_eq!(vm.deg_x(), bp.deg_x);
+        assert_eq!(vm.deg_y(), bp.deg_y);
+        assert_eq!(vm.transpose().deg_x(), bp.deg_y);
+        assert_eq!(vm.transpose().deg_y(), bp.deg_x);
+
+        let bp = BivariatePolynomial::random(0, 0, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        assert_eq!((0, 0), (vm.deg_x(), vm.deg_y()));
+    }
+
+    #[test]
+    fn test_element() {
+        let c = scalar(42);
+        let e = Group::GENERATOR * c;
+
+        let mut bp = BivariatePolynomial::zero(2, 3);
+        assert!(bp.set_coefficient(1, 2, c));
+
+        let vm = VerificationMatrix::from(&bp);
+        assert_eq!(&e, vm.element(1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_is_zero_hole() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // Zero-hole matrix.
+        let mut bp = BivariatePolynomial::random(2, 3, &mut rng);
+        bp.to_zero_hole();
+        let vm = VerificationMatrix::from(&bp);
+        assert!(vm.is_zero_hole());
+        assert!(bool::from(vm.is_zero_hole_ct()));
+        assert!(vm
+            .iter()
+            .skip(1)
+            .all(|(_, _, mij)| !bool::from(mij.is_identity())));
+
+        // Non-zero-hole matrix.
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(!vm.is_zero_hole());
+        assert!(!bool::from(vm.is_zero_hole_ct()));
+
+        // Only the element M_{0,0} is not the identity.
+        let mut bp = BivariatePolynomial::zero(2, 3);
+        assert!(bp.set_coefficient(0, 0, scalar(1)));
+        let vm = VerificationMatrix::from(&bp);
+        assert!(!vm.is_zero_hole());
+        assert!(!bool::from(vm.is_zero_hole_ct()));
+
+        // Composition with other checks.
+        let zero_hole = VerificationMatrix::from(&BivariatePolynomial::zero(2, 3));
+        assert!(bool::from(
+            zero_hole.is_zero_hole_ct() & !vm.is_zero_hole_ct()
+        ));
+    }
+
+    #[test]
+    fn test_element_mut() {
+        let c = scalar(42);
+        let e = Group::GENERATOR * c;
+
+        let mut bp = BivariatePolynomial::zero(2, 3);
+        let mut vm = VerificationMatrix::from(&bp);
+
+        *vm.element_mut(1, 2).unwrap() = e;
+        assert_eq!(vm.element(1, 2), Some(&e));
+
+        assert!(bp.set_coefficient(1, 2, c));
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+
+        // Out of bounds.
+        assert!(vm.element_mut(3, 0).is_none());
+        assert!(vm.element_mut(0, 4).is_none());
+    }
+
+    #[test]
+    fn test_row_and_column() {
+        let b = vec![
+            scalars(&[1, 2, 3, 4]),
+            scalars(&[5, 6, 7, 8]),
+            scalars(&[9, 10, 11, 12]),
+        ];
+        let bp = BivariatePolynomial::with_coefficients(b.clone());
+        let vm = VerificationMatrix::from(&bp);
+        let g = Group::GENERATOR;
+
+        let expected: Vec<_> = b[0].iter().map(|bij| g * bij).collect();
+        assert_eq!(vm.row(0), Some(VerificationVector::new(expected.clone())));
+
+        let expected: Vec<_> = b.iter().map(|bi| g * bi[2]).collect();
+        assert_eq!(
+            vm.column(2),
+            Some(VerificationVector::new(expected.clone()))
+        );
+        assert!(vm.column_iter(2).eq(expected.iter()));
+
+        // Out of range.
+        assert_eq!(vm.row(3), None);
+        assert_eq!(vm.column(4), None);
+        assert_eq!(vm.column_iter(4).count(), 0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let (bp, a) = VerificationMatrix::random(2, 3, &mut rng);
+        let zero = VerificationMatrix::from(&BivariatePolynomial::zero(4, 5));
+
+        // Padding is undone.
+        let padded = &a + &zero;
+        assert_eq!(padded.dimensions(), (5, 6));
+        assert!(padded != a);
+        assert_eq!(padded.normalized(), a);
+        assert!(padded.eq_normalized(&a));
+        assert!(a.eq_normalized(&padded));
+        assert!(a.eq_normalized(&a.extend_zero(2, 7).unwrap()));
+        assert_eq!(a.normalized(), a);
+
+        let mut normalized = padded.clone();
+        normalized.normalize();
+        assert_eq!(normalized, a);
+
+        // Verification results are unchanged.
+        for _ in 0..3 {
+            let x = PrimeField::random(&mut rng);
+            let y = PrimeField::random(&mut rng);
+            let v = bp.eval(&x, &y);
+            assert!(padded.verify(&x, &y, &v));
+            assert!(normalized.verify(&x, &y, &v));
+            assert!(!normalized.verify(&x, &y, &(v + PrimeField::ONE)));
+        }
+
+        // Different matrices remain different.
+        let (_, b) = VerificationMatrix::random(2, 3, &mut rng);
+        assert!(!a.eq_normalized(&b));
+        assert!(!padded.eq_normalized(&(&b + &zero)));
+
+        // Never below 1x1.
+        assert_eq!(zero.normalized().dimensions(), (1, 1));
+        assert!(zero.eq_normalized(&VerificationMatrix::from(&BivariatePolynomial::zero(0, 0))));
+
+        // Only trailing identity rows and columns are stripped.
+        let b = vec![
+            vec![scalar(0), scalar(0), scalar(0), scalar(0)],
+            vec![scalar(0), scalar(0), scalar(0), scalar(0)],
+            vec![scalar(0), scalar(1), scalar(0), scalar(0)],
+            vec![scalar(0), scalar(0), scalar(0), scalar(0)],
+        ];
+        let vm = VerificationMatrix::from(&BivariatePolynomial::with_coefficients(b));
+        let normalized = vm.normalized();
+        assert_eq!(normalized.dimensions(), (3, 2));
+        assert_eq!(normalized.element(2, 1), vm.element(2, 1));
+    }
+
+    #[test]
+    fn test_truncate_and_extend_zero() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = scalar(2);
+        let y = scalar(3);
+
+        // Polynomial of degrees (4, 5) with zero coefficients above (2, 3).
+        let small = BivariatePolynomial::random(2, 3, &mut rng);
+        let mut large = BivariatePolynomial::zero(4, 5);
+        for i in 0..3 {
+            for j in 0..4 {
+                let bij = *small.coefficient(i, j).unwrap();
+                assert!(large.set_coefficient(i, j, bij));
+            }
+        }
+        let v = large.eval(&x, &y);
+        assert_eq!(v, small.eval(&x, &y));
+
+        let vm_small = VerificationMatrix::from(&small);
+        let vm_large = VerificationMatrix::from(&large);
+        assert!(vm_large.verify(&x, &y, &v));
+
+        // Truncation keeps the top-left sub-matrix.
+        let vm = vm_large.truncate(2, 3).expect("degrees should be valid");
+        assert_eq!(vm.dimensions(), (3, 4));
+        assert_eq!(vm, vm_small);
+        assert!(vm.verify(&x, &y, &v));
+
+        let vm = vm_large.truncate(4, 5).expect("degrees should be valid");
+        assert_eq!(vm, vm_large);
+
+        // Extension pads with the identity.
+        let vm = vm_small.extend_zero(4, 5).expect("degrees should be valid");
+        assert_eq!(vm, vm_large);
+        assert!(vm.verify(&x, &y, &v));
+
+        let vm = vm_small.extend_zero(2, 3).expect("degrees should be valid");
+        assert_eq!(vm, vm_small);
+
+        // Invalid degrees.
+        for (deg_x, deg_y) in [(5, 3), (2, 4), (5, 5)] {
+            let res = vm_small.truncate(deg_x, deg_y);
+            assert_eq!(
+                res.unwrap_err().to_string(),
+                Error::InvalidDegrees.to_string()
+            );
+        }
+        for (deg_x, deg_y) in [(1, 3), (2, 2), (0, 10)] {
+            let res = vm_small.extend_zero(deg_x, deg_y);
+            assert_eq!(
+                res.unwrap_err().to_string(),
+                Error::InvalidDegrees.to_string()
+            );
+        }
+        let res = vm_small.extend_zero(65536, 3);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            Error::DimensionsTooLarge.to_string()
+        );
+    }
+
+    #[test]
+    fn test_verify_with_context() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        for (x, y) in [(2, 3), (0, 5), (-1, 0)] {
+            let (x, y) = (scalar(x), scalar(y));
+            let v = bp.eval(&x, &y);
+
+            // Context of the same or higher degrees.
+            for (deg_x, deg_y) in [(2, 3), (4, 3), (2, 6), (5, 5)] {
+                let ctx = VerificationContext::new(x, y, deg_x, deg_y);
+                assert_eq!(ctx.x(), &x);
+                assert_eq!(ctx.y(), &y);
+                assert_eq!(ctx.degrees(), (deg_x, deg_y));
+
+                assert!(vm.verify_with_context(&ctx, &v));
+                assert!(!vm.verify_with_context(&ctx, &(v + scalar(1))));
+            }
+        }
+
+        // Context of lower degrees.
+        let ctx = VerificationContext::new(scalar(2), scalar(3), 2, 2);
+        let v = bp.eval(&scalar(2), &scalar(3));
+        let res = std::panic::catch_unwind(|| vm.verify_with_context(&ctx, &v));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut bp = BivariatePolynomial::zero(2, 3);
+        assert!(bp.set_coefficient(0, 3, scalar(1)));
+        assert!(bp.set_coefficient(2, 1, scalar(2)));
+        let vm = VerificationMatrix::from(&bp);
+
+        // Row-major order.
+        let positions: Vec<_> = vm.iter().map(|(i, j, _)| (i, j)).collect();
+        let expected: Vec<_> = (0..3).flat_map(|i| (0..4).map(move |j| (i, j))).collect();
+        assert_eq!(positions, expected);
+
+        for (i, j, mij) in vm.iter() {
+            assert_eq!(Some(mij), vm.element(i, j));
+        }
+
+        // Non-identity elements.
+        let non_identity: Vec<_> = vm
+            .iter()
+            .filter(|(_, _, mij)| !bool::from(mij.is_identity()))
+            .map(|(i, j, _)| (i, j))
+            .collect();
+        assert_eq!(non_identity, vec![(0, 3), (2, 1)]);
+
+        // Reversed order.
+        let reversed: Vec<_> = vm.iter().rev().map(|(i, j, _)| (i, j)).collect();
+        assert_eq!(reversed, expected.into_iter().rev().collect::<Vec<_>>());
+
+        // Collecting the elements reproduces the byte representation.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let vm = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+
+        let elements = vm.iter();
+        assert_eq!(elements.len(), 12);
+
+        let mut bytes = vec![2, 3];
+        for (_, _, mij) in elements {
+            bytes.extend_from_slice(&mij.to_bytes());
+        }
+        assert_eq!(bytes, vm.to_bytes());
+
+        let mut elements = vm.iter();
+        assert_eq!(elements.next(), Some((0, 0, &vm.m[0])));
+        assert_eq!(elements.next_back(), Some((2, 3, &vm.m[11])));
+        assert_eq!(elements.len(), 10);
+        assert_eq!(vm.iter().nth_back(4).map(|(i, j, _)| (i, j)), Some((1, 3)));
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        let (bp, vm) = VerificationMatrix::random(2, 3, &mut rng);
+        assert_eq!(vm.dimensions(), (3, 4));
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+        assert!(!vm.is_zero_hole());
+
+        let x = PrimeField::random(&mut rng);
+        let y = PrimeField::random(&mut rng);
+        assert!(vm.verify(&x, &y, &bp.eval(&x, &y)));
+
+        let (bp, vm) = VerificationMatrix::random_zero_hole(3, 2, &mut rng);
+        assert_eq!(vm.dimensions(), (4, 3));
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+        assert!(bp.is_zero_hole());
+        assert!(vm.is_zero_hole());
+
+        // The same seed yields the same pair.
+        let mut rng1: StdRng = SeedableRng::from_seed([2u8; 32]);
+        let mut rng2: StdRng = SeedableRng::from_seed([2u8; 32]);
+        let (_, vm1) = VerificationMatrix::random(1, 1, &mut rng1);
+        let (_, vm2) = VerificationMatrix::random(1, 1, &mut rng2);
+        assert_eq!(vm1, vm2);
+    }
+
+    #[test]
+    fn test_rows_iter() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let vm = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+
+        let rows = vm.rows_iter();
+        assert_eq!(rows.len(), 3);
+
+        for (i, mi) in vm.rows_iter().enumerate() {
+            assert_eq!(mi.len(), 4);
+            assert_eq!(Some(VerificationVector::new(mi.to_vec())), vm.row(i));
+        }
+
+        // Collecting the rows reproduces the byte representation.
+        let mut bytes = vec![2, 3];
+        for mij in vm.rows_iter().flatten() {
+            bytes.extend_from_slice(&mij.to_bytes());
+        }
+        assert_eq!(bytes, vm.to_bytes());
+
+        let reversed: Vec<_> = vm.rows_iter().rev().collect();
+        assert_eq!(reversed, vec![&vm.m[8..], &vm.m[4..8], &vm.m[..4]]);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let vmt = vm.transpose();
+
+        assert_eq!(vmt.dimensions(), (4, 3));
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(vm.element(i, j), vmt.element(j, i));
+            }
+        }
+        assert_eq!(vmt.transpose(), vm);
+
+        let x = scalar(2);
+        let y = scalar(3);
+        let v = bp.eval(&x, &y);
+        assert!(vm.verify(&x, &y, &v));
+        assert!(vmt.verify(&y, &x, &v));
+        assert!(!vmt.verify(&x, &y, &v));
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let vm1 = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        let vm2 = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+
+        // Same dimensions.
+        let sum = vm1.checked_add(&vm2).expect("dimensions should match");
+        assert_eq!(sum, &vm1 + &vm2);
+
+        let mut sum = vm1.clone();
+        sum.checked_add_assign(&vm2)
+            .expect("dimensions should match");
+        assert_eq!(sum, &vm1 + &vm2);
+
+        // Different dimensions.
+        for (deg_x, deg_y) in [(1, 3), (2, 2), (3, 3), (3, 2)] {
+            let vm3 =
+                VerificationMatrix::from(&BivariatePolynomial::random(deg_x, deg_y, &mut rng));
+            let rows = deg_x as usize + 1;
+            let cols = deg_y as usize + 1;
+
+            let err = Error::DimensionMismatch {
+                lhs: (3, 4),
+                rhs: (rows, cols),
+            };
+            let res = vm1.checked_add(&vm3);
+            assert_eq!(res.unwrap_err().to_string(), err.to_string());
+
+            let mut sum = vm1.clone();
+            let res = sum.checked_add_assign(&vm3);
+            assert_eq!(res.unwrap_err().to_string(), err.to_string());
+            assert_eq!(sum, vm1);
+
+            let err = Error::DimensionMismatch {
+                lhs: (rows, cols),
+                rhs: (3, 4),
+            };
+            let res = vm3.checked_add(&vm1);
+            assert_eq!(res.unwrap_err().to_string(), err.to_string());
+        }
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        let test_cases = vec![
+            (vec![scalars(&[1])], true),
+            (vec![scalars(&[1, 2]), scalars(&[2, 3])], true),
+            (
+                vec![
+                    scalars(&[1, 2, 3]),
+                    scalars(&[2, 4, 5]),
+                    scalars(&[3, 5, 6]),
+                ],
+                true,
+            ),
+            (vec![scalars(&[1, 2]), scalars(&[3, 4])], false),
+            (
+                vec![
+                    scalars(&[1, 2, 3]),
+                    scalars(&[2, 4, 5]),
+                    scalars(&[3, 6, 6]),
+                ],
+                false,
+            ),
+            (vec![scalars(&[1, 2, 3]), scalars(&[2, 4, 5])], false),
+        ];
+
+        for (b, symmetric) in test_cases {
+            let bp = BivariatePolynomial::with_coefficients(b);
+            let vm = VerificationMatrix::from(&bp);
+            assert_eq!(vm.is_symmetric(), symmetric);
+            assert_eq!(vm.transpose().is_symmetric(), symmetric);
+        }
+    }
+
+    #[test]
+    fn test_is_symmetric_random() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // Symmetric polynomial.
+        let mut bp = BivariatePolynomial::random_symmetric(3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(vm.is_symmetric());
+        assert_eq!(vm.transpose(), vm);
+
+        let x = scalar(2);
+        let y = scalar(3);
+        assert!(vm.verify(&x, &y, &bp.eval(&y, &x)));
+
+        // A single differing element breaks symmetry.
+        bp.b[3][1] += PrimeField::ONE;
+        let vm = VerificationMatrix::from(&bp);
+        assert!(!vm.is_symmetric());
+        assert!(!vm.transpose().is_symmetric());
+
+        // Asymmetric square polynomial.
+        let bp = BivariatePolynomial::random(3, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(!vm.is_symmetric());
+
+        // Asymmetric rectangular polynomials.
+        for (deg_x, deg_y) in [(2, 3), (3, 0), (0, 1)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let vm = VerificationMatrix::from(&bp);
+            assert!(!vm.is_symmetric());
+            assert!(!vm.transpose().is_symmetric());
+        }
+
+        // Constant polynomial.
+        let bp = BivariatePolynomial::random(0, 0, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(vm.is_symmetric());
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x2 = scalar(2);
+        let x3 = scalar(3);
+
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let s = bp.eval_x(&x2).eval(&x3);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(vm.verify(&x2, &x3, &s));
+        assert!(!vm.verify(&x3, &x2, &s));
+    }
+
+    #[test]
+    fn test_verify_ct() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (deg_x, deg_y) in [(0, 0), (2, 3), (3, 2), (4, 4)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let vm = VerificationMatrix::from(&bp);
+
+            for _ in 0..3 {
+                let x = PrimeField::random(&mut rng);
+                let y = PrimeField::random(&mut rng);
+                let v = bp.eval(&x, &y);
+
+                let test_cases = vec![
+                    (x, y, v, true),
+                    (x, y, v + PrimeField::ONE, false),
+                    (x, y, PrimeField::ZERO, false),
+                    (x + PrimeField::ONE, y, v, deg_x == 0),
+                    (x, y + PrimeField::ONE, v, deg_y == 0),
+                ];
+                for (x, y, v, valid) in test_cases {
+                    let verified = vm.verify_ct(&x, &y, &v);
+                    assert_eq!(bool::from(verified), valid);
+                    assert_eq!(vm.verify(&x, &y, &v), valid);
+                }
+            }
+        }
+
+        // Choices compose with other checks.
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let (x, y) = (scalar(2), scalar(3));
+        let valid = vm.verify_ct(&x, &y, &bp.eval(&x, &y));
+        let invalid = vm.verify_ct(&y, &x, &bp.eval(&x, &y));
+        assert!(bool::from(valid & !vm.is_zero_hole_ct()));
+        assert!(!bool::from(valid & invalid));
+    }
+
+    #[test]
+    fn test_verify_naive() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (deg_x, deg_y) in [(0, 0), (1, 2), (2, 4), (5, 10)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let vm = VerificationMatrix::from(&bp);
+
+            for (x, y) in [(scalar(0), scalar(0)), (scalar(2), scalar(-3))] {
+                let s = bp.eval(&x, &y);
+                assert!(vm.verify(&x, &y, &s));
+                assert!(vm.verify_naive(&x, &y, &s));
+
+                let s = s + scalar(1);
+                assert!(!vm.verify(&x, &y, &s));
+                assert!(!vm.verify_naive(&x, &y, &s));
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let mut points: Vec<_> = (1..=10)
+            .map(|k| {
+                let x = scalar(k);
+                let y = scalar(2 * k + 1);
+                (x, y, bp.eval(&x, &y))
+            })
+            .collect();
+
+        // Valid points.
+        assert!(vm.verify_batch(&[], &mut rng));
+        assert!(vm.verify_batch(&points[..1], &mut rng));
+        assert!(vm.verify_batch(&points, &mut rng));
+        assert_eq!(vm.find_invalid_point(&points), None);
+
+        // One invalid point.
+        points[7].2 += scalar(1);
+        assert!(!vm.verify_batch(&points[7..8], &mut rng));
+        assert!(!vm.verify_batch(&points, &mut rng));
+        assert_eq!(vm.find_invalid_point(&points), Some(7));
+
+        // Two invalid points whose differences cancel out.
+        points[3].2 -= scalar(1);
+        assert!(!vm.verify_batch(&points, &mut rng));
+        assert_eq!(vm.find_invalid_point(&points), Some(3));
+
+        // Large bundle.
+        let bp = BivariatePolynomial::random(5, 10, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let mut points: Vec<_> = (1..=50)
+            .map(|k| {
+                let x = scalar(k);
+                let y = scalar(-k);
+                (x, y, bp.eval(&x, &y))
+            })
+            .collect();
+        assert!(vm.verify_batch(&points, &mut rng));
+
+        points[49].0 = scalar(51);
+        assert!(!vm.verify_batch(&points, &mut rng));
+        assert_eq!(vm.find_invalid_point(&points), Some(49));
+    }
+
+    #[test]
+    fn test_verify_x_batch() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = scalar(5);
+
+        // Dealings of different dimensions from many dealers.
+        let bps: Vec<_> = (0..10)
+            .map(|k| BivariatePolynomial::random(2 + k % 3, 3 + k % 2, &mut rng))
+            .collect();
+        let vms: Vec<_> = bps.iter().map(VerificationMatrix::from).collect();
+        let mut ps: Vec<_> = bps.iter().map(|bp| bp.eval_x(&x)).collect();
+
+        // Valid polynomials.
+        let items: Vec<_> = vms.iter().zip(ps.iter()).collect();
+        assert!(VerificationMatrix::verify_x_batch(&[], &x, &mut rng));
+        assert!(VerificationMatrix::verify_x_batch(&items, &x, &mut rng));
+        assert!(VerificationMatrix::find_invalid_x(&items, &x).is_empty());
+
+        // Wrong x.
+        assert!(!VerificationMatrix::verify_x_batch(
+            &items,
+            &scalar(6),
+            &mut rng
+        ));
+
+        // Invalid coefficients.
+        ps[2].a[1] += scalar(1);
+        ps[7].a[0] -= scalar(1);
+        let items: Vec<_> = vms.iter().zip(ps.iter()).collect();
+        assert!(!VerificationMatrix::verify_x_batch(&items, &x, &mut rng));
+        assert_eq!(VerificationMatrix::find_invalid_x(&items, &x), vec![2, 7]);
+
+        // Invalid sizes.
+        let mut ps: Vec<_> = bps.iter().map(|bp| bp.eval_x(&x)).collect();
+        ps[4].a.push(scalar(0));
+        ps[9].a.pop();
+        let items: Vec<_> = vms.iter().zip(ps.iter()).collect();
+        assert!(!VerificationMatrix::verify_x_batch(&items, &x, &mut rng));
+        assert_eq!(VerificationMatrix::find_invalid_x(&items, &x), vec![4, 9]);
+    }
+
+    #[test]
+    fn test_verification_polynomial_for_x() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x2 = scalar(2);
+        let x3 = scalar(3);
+
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let p = bp.eval_y(&x2);
+
+        let vv = vm.verification_vector_for_x(&x2);
+        assert!(vv.is_from(&p));
+
+        let vv = vm.verification_vector_for_x(&x3);
+        assert!(!vv.is_from(&p));
+    }
+
+    #[test]
+    fn test_verification_polynomial_for_y() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x2 = scalar(2);
+        let x3 = scalar(3);
+
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let p = bp.eval_x(&x2);
+
+        let vv = vm.verification_vector_for_y(&x2);
+        assert!(vv.is_from(&p));
+
+        let vv = vm.verification_vector_for_y(&x3);
+        assert!(!vv.is_from(&p));
+    }
+
+    #[test]
+    fn test_verification_vector_verify() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let x = scalar(2);
+        let y = scalar(5);
+        let v = bp.eval(&x, &y);
+
+        // Vector for B(x,y) with fixed y, verified at x.
+        let vv = vm.verification_vector_for_x(&y);
+        assert!(vv.verify(&x, &v));
+        assert!(!vv.verify(&x, &(v + scalar(1))));
+        assert!(!vv.verify(&y, &v));
+
+        // Vector for B(x,y) with fixed x, verified at y.
+        let vv = vm.verification_vector_for_y(&x);
+        assert!(vv.verify(&y, &v));
+        assert!(!vv.verify(&y, &(v + scalar(1))));
+        assert!(!vv.verify(&x, &v));
+    }
+
+    #[test]
+    fn test_verification_vectors_for_x() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let ys = scalars(&[0, 1, 2, -3, 100]);
+
+        let vvs = vm.verification_vectors_for_x(&ys);
+        assert_eq!(vvs.len(), ys.len());
+        for (vv, y) in vvs.iter().zip(&ys) {
+            assert_eq!(vv, &vm.verification_vector_for_x(y));
+        }
+
+        assert!(vm.verification_vectors_for_x(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_interpolate_rows() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let (_, vm) = VerificationMatrix::random(2, 3, &mut rng);
+        let xs = scalars(&[1, 2, -3, 100]);
+        let points: Vec<_> = xs
+            .iter()
+            .map(|x| (*x, vm.verification_vector_for_y(x)))
+            .collect();
+
+        // Exactly deg_x + 1 points recover the matrix.
+        let restored = VerificationMatrix::interpolate_rows(&points[..3]);
+        assert_eq!(restored.as_ref(), Some(&vm));
+        let restored = VerificationMatrix::interpolate_rows(&points[1..]);
+        assert_eq!(restored.as_ref(), Some(&vm));
+
+        // More points recover the matrix with zero rows appended.
+        let restored =
+            VerificationMatrix::interpolate_rows(&points).expect("interpolation should succeed");
+        assert_eq!(restored, vm.extend_zero(3, 3).unwrap());
+
+        // Fewer points recover a different matrix.
+        let restored = VerificationMatrix::interpolate_rows(&points[..2])
+            .expect("interpolation should succeed");
+        assert_eq!(restored.dimensions(), (2, 4));
+        assert_ne!(restored, vm.truncate(1, 3).unwrap());
+
+        // A single point gives a single row.
+        let restored = VerificationMatrix::interpolate_rows(&points[..1])
+            .expect("interpolation should succeed");
+        assert_eq!(restored.row(0), Some(points[0].1.clone()));
+
+        // No points.
+        assert!(VerificationMatrix::interpolate_rows(&[]).is_none());
+
+        // Duplicate x values.
+        let mut duplicate = points.clone();
+        duplicate[2].0 = xs[0];
+        assert!(VerificationMatrix::interpolate_rows(&duplicate).is_none());
+
+        // Inconsistent vector lengths.
+        let mut inconsistent = points.clone();
+        inconsistent[1].1 = vm.truncate(2, 2).unwrap().verification_vector_for_y(&xs[1]);
+        assert!(VerificationMatrix::interpolate_rows(&inconsistent).is_none());
+
+        // Empty vectors.
+        let empty: Vec<_> = xs
+            .iter()
+            .map(|x| (*x, VerificationVector::new(vec![])))
+            .collect();
+        assert!(VerificationMatrix::interpolate_rows(&empty).is_none());
+    }
+
+    #[test]
+    fn test_from_with_base() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let base = Group::random(&mut rng);
+
+        // Small matrices are computed without a table, large ones with.
+        for (deg_x, deg_y) in [(0, 0), (1, 2), (3, 4)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let vm = VerificationMatrix::from_with_base(&bp, &base);
+
+            for (i, j, mij) in vm.iter() {
+                assert_eq!(*mij, base * bp.coefficient(i, j).unwrap());
+            }
+
+            let x = PrimeField::random(&mut rng);
+            let y = PrimeField::random(&mut rng);
+            let v = bp.eval(&x, &y);
+            assert!(vm.verify_with_base(&base, &x, &y, &v));
+            assert!(!vm.verify_with_base(&base, &x, &y, &(v + PrimeField::ONE)));
+            assert!(!vm.verify(&x, &y, &v));
+
+            let vm = VerificationMatrix::from_with_base(&bp, &Group::generator());
+            assert_eq!(vm, VerificationMatrix::from(&bp));
+            assert!(vm.verify_with_base(&Group::generator(), &x, &y, &v));
+        }
+    }
+
+    #[test]
+    fn test_zero() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (rows, cols) in [(1, 1), (3, 3), (2, 5), (5, 2)] {
+            let zero = VerificationMatrix::zero(rows, cols).unwrap();
+            assert_eq!(zero.dimensions(), (rows, cols));
+            assert!(zero.is_zero());
+            assert!(bool::from(zero.is_zero_ct()));
+            assert!(zero.verify(&scalar(2), &scalar(3), &scalar(0)));
+
+            // Adding pads to the larger dimensions.
+            let bp = BivariatePolynomial::random(2, 3, &mut rng);
+            let vm = VerificationMatrix::from(&bp);
+            let sum = &zero + &vm;
+            assert_eq!(sum.dimensions(), (max(rows, 3), max(cols, 4)));
+            assert_eq!(sum, vm.extend_zero(sum.deg_x(), sum.deg_y()).unwrap());
+            assert!(sum.eq_normalized(&vm));
+            assert!(!sum.is_zero());
+
+            // A single non-identity element.
+            let mut vm = zero.clone();
+            *vm.element_mut(rows - 1, cols - 1).unwrap() = Group::generator();
+            assert!(!vm.is_zero());
+            assert!(!bool::from(vm.is_zero_ct()));
+        }
+
+        // Difference of equal matrices.
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        assert!((&vm - &vm).is_zero());
+
+        // Invalid dimensions.
+        assert!(VerificationMatrix::zero(0, 1).is_err());
+        assert!(VerificationMatrix::zero(1, 0).is_err());
+        assert!(VerificationMatrix::zero(usize::MAX, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_first_row_commitment() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(3, 5, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let vv = vm.first_row_commitment();
+        assert_eq!(Some(vv.clone()), vm.row(0));
+
+        let bytes = vm.to_bytes_first_row().expect("encoding should succeed");
+        assert_eq!(bytes.len(), VerificationVector::byte_size(6));
+        assert_eq!((bytes.len() - 1) * 4, vm.to_bytes().len() - 2); // Headers aside.
+        let restored = VerificationVector::from_bytes(&bytes).unwrap();
+        assert_eq!(restored, vv);
+
+        // Shares of the secret verify the same under both commitments.
+        let zero = PrimeField::ZERO;
+        for y in [scalar(1), scalar(2), PrimeField::random(&mut rng)] {
+            let v = bp.eval(&zero, &y);
+            assert!(vm.verify(&zero, &y, &v));
+            assert!(restored.verify(&y, &v));
+
+            let v = v + PrimeField::ONE;
+            assert!(!vm.verify(&zero, &y, &v));
+            assert!(!restored.verify(&y, &v));
+        }
+
+        // Single-row matrix.
+        let bp = BivariatePolynomial::random(0, 2, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let bytes = vm.to_bytes_first_row().expect("encoding should succeed");
+        assert_eq!(bytes.len() + 1, vm.to_bytes().len());
+
+        // Matrices with more than 256 columns are valid, but their first row
+        // doesn't fit the byte representation of a verification vector.
+        let vm = VerificationMatrix::zero(1, 300).unwrap();
+        assert!(matches!(
+            vm.to_bytes_first_row(),
+            Err(Error::DimensionsTooLarge)
+        ));
+        let vm = VerificationMatrix::zero(1, 256).unwrap();
+        assert!(vm.to_bytes_first_row().is_ok());
+    }
+
+    #[test]
+    fn test_verify_x() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x2 = scalar(2);
+
+        // Asymmetric bivariate polynomial.
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let p = bp.eval_x(&x2);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(vm.verify_x(&x2, &p));
+        assert!(!vm.verify_y(&x2, &p)); // Invalid degree.
+
+        // Symmetric bivariate polynomial.
+        let bp = BivariatePolynomial::random(2, 2, &mut rng);
+        let p = bp.eval_x(&x2);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(vm.verify_x(&x2, &p));
+        assert!(!vm.verify_y(&x2, &p)); // Valid degree, but verification failed.
+    }
+
+    #[test]
+    fn test_verify_y() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let y2 = scalar(2);
+
+        // Asymmetric bivariate polynomial.
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let p = bp.eval_y(&y2);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(!vm.verify_x(&y2, &p)); // Invalid degree.
+        assert!(vm.verify_y(&y2, &p));
+
+        // Symmetric bivariate polynomial.
+        let bp = BivariatePolynomial::random(2, 2, &mut rng);
+        let p = bp.eval_y(&y2);
+        let vm = VerificationMatrix::from(&bp);
+        assert!(!vm.verify_x(&y2, &p)); // Valid degree, but verification failed.
+        assert!(vm.verify_y(&y2, &p));
+    }
+
+    #[test]
+    fn test_verify_x_y_with_table() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let table = GeneratorTable::new();
+        let x = scalar(2);
+        let y = scalar(3);
+
+        for (deg_x, deg_y) in [(0, 0), (2, 3), (4, 4)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let vm = VerificationMatrix::from(&bp);
+
+            let p = bp.eval_x(&x);
+            assert!(vm.verify_x_with_table(&x, &p, &table));
+            assert_eq!(vm.verify_x_with_table(&y, &p, &table), deg_x == 0);
+
+            let p = bp.eval_y(&y);
+            assert!(vm.verify_y_with_table(&y, &p, &table));
+            assert_eq!(vm.verify_y_with_table(&x, &p, &table), deg_y == 0);
+
+            // Tampered coefficients and polynomials of invalid size.
+            let mut p = bp.eval_x(&x);
+            p.a[0] += PrimeField::ONE;
+            assert!(!vm.verify_x_with_table(&x, &p, &table));
+            assert!(!vm.verify_x_with_table(&x, &Polynomial::zero(deg_y + 1), &table));
+            assert!(!vm.verify_y_with_table(&y, &Polynomial::zero(deg_x + 1), &table));
+        }
+    }
+
+    #[test]
+    fn test_verify_x_y_debug() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = scalar(2);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        // Valid polynomials.
+        let px = bp.eval_x(&x);
+        let py = bp.eval_y(&x);
+        assert_eq!(vm.verify_x_debug(&x, &px), Ok(()));
+        assert_eq!(vm.verify_y_debug(&x, &py), Ok(()));
+
+        // Invalid coefficient.
+        let mut p = px.clone();
+        p.set_coefficient(2, scalar(1));
+        assert_eq!(vm.verify_x_debug(&x, &p), Err(2));
+        assert!(!vm.verify_x(&x, &p));
+
+        let mut p = py.clone();
+        p.set_coefficient(1, scalar(1));
+        assert_eq!(vm.verify_y_debug(&x, &p), Err(1));
+        assert!(!vm.verify_y(&x, &p));
+
+        // Invalid point.
+        assert_eq!(vm.verify_x_debug(&scalar(3), &px), Err(0));
+        assert_eq!(vm.verify_y_debug(&scalar(3), &py), Err(0));
+
+        // Missing coefficients.
+        let p =
+            Polynomial::with_coefficients((0..3).map(|j| *px.coefficient(j).unwrap()).collect());
+        assert_eq!(vm.verify_x_debug(&x, &p), Err(3));
+        let p = Polynomial::with_coefficients(vec![*py.coefficient(0).unwrap()]);
+        assert_eq!(vm.verify_y_debug(&x, &p), Err(1));
+
+        // Excess coefficients.
+        let coefficients = (0..4).map(|j| *px.coefficient(j).unwrap());
+        let p = Polynomial::with_coefficients(coefficients.chain([scalar(0)]).collect());
+        assert_eq!(vm.verify_x_debug(&x, &p), Err(4));
+        let coefficients = (0..3).map(|i| *py.coefficient(i).unwrap());
+        let p = Polynomial::with_coefficients(coefficients.chain([scalar(0)]).collect());
+        assert_eq!(vm.verify_y_debug(&x, &p), Err(3));
+    }
+
+    #[test]
+    fn test_verify_x_y_ct() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = scalar(2);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let px = bp.eval_x(&x);
+        let py = bp.eval_y(&x);
+        let mut wrong_px = px.clone();
+        assert!(wrong_px.set_coefficient(1, scalar(7)));
+        let mut wrong_py = py.clone();
+        assert!(wrong_py.set_coefficient(2, scalar(7)));
+
+        // Shorter and longer polynomials, with and without valid prefixes.
+        let truncate = |p: &Polynomial, n| Polynomial::with_coefficients(p.a[..n].to_vec());
+        let extend = |p: &Polynomial, c| {
+            let mut a = p.a.clone();
+            a.push(scalar(c));
+            Polynomial::with_coefficients(a)
+        };
+
+        let test_cases_x = vec![
+            px.clone(),
+            wrong_px,
+            truncate(&px, 3),
+            truncate(&px, 1),
+            extend(&px, 0),
+            extend(&px, 5),
+            py.clone(),
+        ];
+        for p in test_cases_x {
+            assert_eq!(vm.verify_x_ct(&x, &p), vm.verify_x(&x, &p));
+        }
+        assert!(vm.verify_x_ct(&x, &px));
+
+        let test_cases_y = vec![
+            py.clone(),
+            wrong_py,
+            truncate(&py, 2),
+            truncate(&py, 1),
+            extend(&py, 0),
+            extend(&py, 5),
+            px,
+        ];
+        for p in test_cases_y {
+            assert_eq!(vm.verify_y_ct(&x, &p), vm.verify_y(&x, &p));
+        }
+        assert!(vm.verify_y_ct(&x, &py));
+    }
+
+    #[test]
+    fn test_verify_share() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let p = scalar(2);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let row = bp.eval_x(&p);
+        let col = bp.eval_y(&p);
+
+        // Valid share.
+        assert!(vm.verify_share(&p, &row, &col));
+
+        // Invalid row polynomial.
+        let mut wrong_row = row.clone();
+        assert!(wrong_row.set_coefficient(1, scalar(7)));
+        assert!(!vm.verify_share(&p, &wrong_row, &col));
+
+        // Invalid column polynomial.
+        let mut wrong_col = col.clone();
+        assert!(wrong_col.set_coefficient(2, scalar(7)));
+        assert!(!vm.verify_share(&p, &row, &wrong_col));
+
+        // Both polynomials invalid, but consistent on the diagonal.
+        let mut wrong_row = row.clone();
+        let mut wrong_col = col.clone();
+        assert!(wrong_row.set_coefficient(0, row.a[0] + scalar(1)));
+        assert!(wrong_col.set_coefficient(0, col.a[0] + scalar(1)));
+        assert!(wrong_row.eval(&p) == wrong_col.eval(&p));
+        assert!(!vm.verify_share(&p, &wrong_row, &wrong_col));
+
+        // Polynomials of invalid size.
+        let short_row = Polynomial::with_coefficients(row.a[..3].to_vec());
+        assert!(!vm.verify_share(&p, &short_row, &col));
+        let mut long_col = col.a.clone();
+        long_col.push(scalar(0));
+        let long_col = Polynomial::with_coefficients(long_col);
+        assert!(!vm.verify_share(&p, &row, &long_col));
+
+        // Swapped polynomials.
+        assert!(!vm.verify_share(&p, &col, &row));
+
+        // Polynomials of another shareholder.
+        assert!(!vm.verify_share(&scalar(3), &row, &col));
+
+        // Polynomials of different shareholders.
+        let other_col = bp.eval_y(&scalar(3));
+        assert!(!vm.verify_share(&p, &row, &other_col));
+
+        // Asymmetric square bivariate polynomial, where the row and the column
+        // polynomials are of the same size, but not interchangeable.
+        let bp = BivariatePolynomial::random(2, 2, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let row = bp.eval_x(&p);
+        let col = bp.eval_y(&p);
+        assert!(vm.verify_share(&p, &row, &col));
+        assert!(!vm.verify_share(&p, &col, &row));
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let restored =
+            VerificationMatrix::from_bytes(&vm.to_bytes()).expect("deserialization should succeed");
+
+        assert_eq!(vm, restored);
+    }
+
+    #[test]
+    fn test_hex() {
+        let b = vec![
+            vec![PrimeField::from_u64(1), PrimeField::from_u64(2)],
+            vec![PrimeField::from_u64(3), PrimeField::from_u64(4)],
+        ];
+        let vm = VerificationMatrix::from(&BivariatePolynomial::with_coefficients(b));
+
+        let hex = vm.to_hex();
+        assert_eq!(format!("{:x}", vm), hex);
+        assert_eq!(&hex[..6], "0101:0");
+        assert_eq!(hex.len(), 2 * vm.to_bytes().len() + 1);
+        assert!(hex
+            .chars()
+            .all(|c| c == ':' || matches!(c, '0'..='9' | 'a'..='f')));
+        assert_eq!(VerificationMatrix::from_hex(&hex), Some(vm.clone()));
+
+        // Without the separator and in uppercase.
+        let plain = hex.replace(':', "");
+        assert_eq!(VerificationMatrix::from_hex(&plain), Some(vm.clone()));
+        assert_eq!(
+            VerificationMatrix::from_hex(&plain.to_uppercase()),
+            Some(vm.clone())
+        );
+
+        // Extended format.
+        let b = vec![vec![PrimeField::ONE; 300]];
+        let vm = VerificationMatrix::from(&BivariatePolynomial::with_coefficients(b));
+        let hex = vm.to_hex();
+        assert_eq!(&hex[..15], "ffff0100002b01:");
+        assert_eq!(VerificationMatrix::from_hex(&hex), Some(vm));
+
+        // Invalid inputs.
+        let test_cases = vec![
+            "",
+            ":",
+            "0101",
+            &hex[..hex.len() - 1],
+            &hex[..hex.len() - 2],
+            "0101:0+",
+            "01:01",
+        ];
+        for hex in test_cases {
+            assert!(VerificationMatrix::from_hex(hex).is_none());
+        }
+
+        let mut misplaced = plain.clone();
+        misplaced.insert(2, ':');
+        assert!(VerificationMatrix::from_hex(&misplaced).is_none());
+
+        let mut invalid = plain;
+        invalid.replace_range(4..6, "xx");
+        assert!(VerificationMatrix::from_hex(&invalid).is_none());
+    }
+
+    #[test]
+    fn test_from_bytes_checked() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // All elements of a prime-order group pass the check.
+        for (deg_x, deg_y) in [(0, 0), (2, 3)] {
+            let (_, vm) = VerificationMatrix::random(deg_x, deg_y, &mut rng);
+            let bytes = vm.to_bytes();
+            let restored = VerificationMatrix::from_bytes_checked(&bytes);
+            assert_eq!(restored.as_ref(), Ok(&vm));
+        }
+
+        let (_, vm) = VerificationMatrix::random_zero_hole(2, 3, &mut rng);
+        let restored = VerificationMatrix::from_bytes_checked(&vm.to_bytes());
+        assert_eq!(restored.as_ref(), Ok(&vm));
+
+        // Decoding errors are reported as usual.
+        let res = VerificationMatrix::from_bytes_checked(&[]);
+        assert_eq!(res, Err(DecodeError::TooShort));
+    }
+
+    #[test]
+    fn test_from_bytes_strict() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let strict = DecodeOptions::strict();
+
+        // Valid dealing.
+        let (_, vm) = VerificationMatrix::random(2, 3, &mut rng);
+        let bytes = vm.to_bytes();
+        let restored = VerificationMatrix::from_bytes_strict(&bytes, &strict);
+        assert_eq!(restored.as_ref(), Ok(&vm));
+        let restored = VerificationMatrix::from_bytes_strict(&bytes, &DecodeOptions::default());
+        assert_eq!(restored.as_ref(), Ok(&vm));
+
+        // Decoding errors are reported as usual.
+        let res = VerificationMatrix::from_bytes_strict(&bytes[..10], &strict);
+        assert_eq!(
+            res,
+            Err(DecodeError::LengthMismatch {
+                expected: bytes.len(),
+                got: 10
+            })
+        );
+
+        // Zero-hole dealing.
+        let (_, vm) = VerificationMatrix::random_zero_hole(2, 3, &mut rng);
+        let bytes = vm.to_bytes();
+        let res = VerificationMatrix::from_bytes_strict(&bytes, &strict);
+        assert_eq!(res, Err(DecodeError::IdentityElement { row: 0, col: 0 }));
+        let opts = DecodeOptions {
+            forbid_identity: vec![],
+            ..strict.clone()
+        };
+        let restored = VerificationMatrix::from_bytes_strict(&bytes, &opts);
+        assert_eq!(restored.as_ref(), Ok(&vm));
+
+        // Identity at other positions.
+        let mut bp = BivariatePolynomial::random(2, 3, &mut rng);
+        assert!(bp.set_coefficient(1, 2, PrimeField::ZERO));
+        let bytes = VerificationMatrix::from(&bp).to_bytes();
+        let opts = DecodeOptions {
+            forbid_identity: vec![(0, 0), (1, 2), (5, 5)],
+            ..Default::default()
+        };
+        let res = VerificationMatrix::from_bytes_strict(&bytes, &opts);
+        assert_eq!(res, Err(DecodeError::IdentityElement { row: 1, col: 2 }));
+        let opts = DecodeOptions {
+            forbid_identity: vec![(0, 0), (5, 5)],
+            ..Default::default()
+        };
+        assert!(VerificationMatrix::from_bytes_strict(&bytes, &opts).is_ok());
+
+        // Identity leading row or column.
+        let b = vec![
+            vec![scalar(1), scalar(2), scalar(0)],
+            vec![scalar(0), scalar(3), scalar(0)],
+            vec![scalar(0), scalar(0), scalar(0)],
+        ];
+        let bytes = VerificationMatrix::from(&BivariatePolynomial::with_coefficients(b)).to_bytes();
+        let res = VerificationMatrix::from_bytes_strict(&bytes, &strict);
+        assert_eq!(res, Err(DecodeError::IdentityLeadingRow));
+        let opts = DecodeOptions {
+            forbid_identity_leading_row: false,
+            ..strict.clone()
+        };
+        let res = VerificationMatrix::from_bytes_strict(&bytes, &opts);
+        assert_eq!(res, Err(DecodeError::IdentityLeadingColumn));
+        let opts = DecodeOptions {
+            forbid_identity_leading_column: false,
+            ..opts
+        };
+        assert!(VerificationMatrix::from_bytes_strict(&bytes, &opts).is_ok());
+
+        // A single-element matrix leads with its only element.
+        let bytes = VerificationMatrix::from(&BivariatePolynomial::zero(0, 0)).to_bytes();
+        let opts = DecodeOptions {
+            forbid_identity_leading_row: true,
+            ..Default::default()
+        };
+        let res = VerificationMatrix::from_bytes_strict(&bytes, &opts);
+        assert_eq!(res, Err(DecodeError::IdentityLeadingRow));
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let bytes = vm.to_bytes();
+        let size = VerificationMatrix::element_byte_size();
+
+        let restored = VerificationMatrix::try_from_bytes(&bytes).expect("decoding should succeed");
+        assert_eq!(restored, vm);
+
+        let mut corrupted = bytes.clone();
+        corrupted[2 + (4 + 2) * size] = 0xFF; // m_{1,2}
+
+        let test_cases = vec![
+            (vec![], DecodeError::TooShort),
+            (vec![2], DecodeError::TooShort),
+            (
+                vec![2, 3],
+                DecodeError::LengthMismatch {
+                    expected: bytes.len(),
+                    got: 2,
+                },
+            ),
+            (
+                bytes[..bytes.len() - 1].to_vec(),
+                DecodeError::LengthMismatch {
+                    expected: bytes.len(),
+                    got: bytes.len() - 1,
+                },
+            ),
+            (corrupted, DecodeError::InvalidElement { row: 1, col: 2 }),
+        ];
+
+        for (bytes, err) in test_cases {
+            let res = VerificationMatrix::try_from_bytes(&bytes);
+            assert_eq!(res.unwrap_err(), err);
+            assert!(VerificationMatrix::from_bytes(&bytes).is_none());
+        }
+    }
+
+    #[test]
+    fn test_try_from_bytes_bounded() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let bytes = vm.to_bytes();
+
+        for (max_deg_x, max_deg_y) in [(2, 3), (3, 3), (255, 255), (usize::MAX, usize::MAX)] {
+            let restored = VerificationMatrix::try_from_bytes_bounded(&bytes, max_deg_x, max_deg_y);
+            assert_eq!(restored.as_ref(), Ok(&vm));
+        }
+
+        // Degrees exceeding the bounds.
+        for (max_deg_x, max_deg_y) in [(1, 3), (2, 2), (0, 0)] {
+            let err = VerificationMatrix::try_from_bytes_bounded(&bytes, max_deg_x, max_deg_y);
+            assert_eq!(
+                err,
+                Err(DecodeError::DegreesExceeded {
+                    max: (max_deg_x, max_deg_y),
+                    got: (2, 3)
+                })
+            );
+        }
+
+        // Header claiming large degrees is rejected regardless of the length.
+        let header = [0xFF, 0xFF, 0x01, 0xFF, 0xFF, 0xFF, 0xFF];
+        let err = VerificationMatrix::try_from_bytes_bounded(&header, 10, 10).unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::DegreesExceeded {
+                max: (10, 10),
+                got: (65535, 65535)
+            }
+        );
+
+        // Invalid input within bounds.
+        assert!(VerificationMatrix::try_from_bytes_bounded(&bytes[1..], 10, 10).is_err());
+        let err = VerificationMatrix::try_from_bytes_bounded(&[], 10, 10).unwrap_err();
+        assert_eq!(err, DecodeError::TooShort);
+
+        // A large matrix is rejected before any element is decoded, as none
+        // of its elements is valid.
+        let size = VerificationMatrix::element_byte_size();
+        let mut bytes = vec![0xFF; 2 + 200 * 200 * size];
+        bytes[..2].copy_from_slice(&[199, 199]);
+
+        let err = VerificationMatrix::try_from_bytes_bounded(&bytes, 10, 20).unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::DegreesExceeded {
+                max: (10, 20),
+                got: (199, 199)
+            }
+        );
+
+        let err = VerificationMatrix::try_from_bytes(&bytes).unwrap_err();
+        assert_eq!(err, DecodeError::InvalidElement { row: 0, col: 0 });
+    }
+
+    #[test]
+    fn test_from_bytes_fixed() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let bytes = vm.to_bytes();
+        let size = VerificationMatrix::element_byte_size();
+
+        let restored = VerificationMatrix::from_bytes_fixed(&bytes, 3, 4);
+        assert_eq!(restored, Some(vm));
+
+        // Unexpected dimensions.
+        assert!(VerificationMatrix::from_bytes_fixed(&bytes, 4, 3).is_none());
+        assert!(VerificationMatrix::from_bytes_fixed(&bytes, 3, 3).is_none());
+        assert!(VerificationMatrix::from_bytes_fixed(&bytes, 0, 4).is_none());
+        assert!(VerificationMatrix::from_bytes_fixed(&bytes, 257, 4).is_none());
+        assert!(VerificationMatrix::from_bytes_fixed(&bytes, 65537, 4).is_none());
+
+        // Header mismatch (same length, transposed dimensions).
+        let mut corrupted = bytes.clone();
+        corrupted.swap(0, 1);
+        assert!(VerificationMatrix::from_bytes_fixed(&corrupted, 3, 4).is_none());
+        let restored = VerificationMatrix::from_bytes_fixed(&corrupted, 4, 3);
+        assert_eq!(restored.map(|vm| vm.dimensions()), Some((4, 3)));
+
+        // Invalid element.
+        let mut corrupted = bytes.clone();
+        corrupted[2 + 11 * size] = 0xFF;
+        assert!(VerificationMatrix::from_bytes_fixed(&corrupted, 3, 4).is_none());
+
+        // Invalid length.
+        assert!(VerificationMatrix::from_bytes_fixed(&bytes[1..], 3, 4).is_none());
+        assert!(VerificationMatrix::from_bytes_fixed(&[], 3, 4).is_none());
+    }
+
+    #[test]
+    fn test_serialization_lossy() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let size = VerificationMatrix::element_byte_size();
+
+        // Intact matrix.
+        let (pm, missing) = VerificationMatrix::from_bytes_lossy(&vm.to_bytes())
+            .expect("deserialization should succeed");
+        assert!(missing.is_empty());
+        assert_eq!(pm.finalize().expect("matrix should be complete"), vm);
+
+        // Corrupted elements (invalid encoding tag).
+        let corrupted = vec![(0, 1), (1, 3), (2, 0)];
+        let mut bytes = vm.to_bytes();
+        for &(i, j) in &corrupted {
+            bytes[2 + (i * 4 + j) * size] = 0xFF;
+        }
+        assert!(VerificationMatrix::from_bytes(&bytes).is_none());
+
+        let (pm, missing) =
+            VerificationMatrix::from_bytes_lossy(&bytes).expect("deserialization should succeed");
+        assert_eq!(missing, corrupted);
+        assert_eq!(pm.dimensions(), (3, 4));
+
+        // Invalid header or length.
+        assert!(VerificationMatrix::from_bytes_lossy(&[]).is_none());
+        assert!(VerificationMatrix::from_bytes_lossy(&bytes[..bytes.len() - 1]).is_none());
+    }
+
+    #[test]
+    fn test_checked_byte_size() {
+        assert_eq!(checked_byte_size(2, 49, 3, 4), Some(2 + 3 * 4 * 49));
+        assert_eq!(checked_byte_size(2, 49, usize::MAX, 1), None);
+        assert_eq!(checked_byte_size(2, 49, usize::MAX / 2, 2), None);
+        assert_eq!(checked_byte_size(usize::MAX, 1, 1, 1), None);
+
+        // The size of the largest matrices doesn't fit into 32 bits.
+        let size = checked_byte_size(7, 49, 65536, 65536);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(size, Some(7 + 65536 * 65536 * 49));
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(size, None);
+        assert_eq!(
+            u32::try_from(7 + 65536 * 65536 * 49u64).ok(),
+            None::<u32>,
+            "size should overflow on 32-bit targets"
+        );
+    }
+
+    #[test]
+    fn test_short_buffers() {
+        // Headers of the largest matrices followed by a few bytes.
+        let test_cases = vec![
+            (vec![0xFF, 0xFF, 0x01, 0xFF, 0xFF, 0xFF, 0xFF], 65536, 65536),
+            (vec![0xFF, 0xFF, 0x01, 0xFF, 0xFF, 0x00, 0x00], 65536, 1),
+            (vec![0xFE, 0xFF], 255, 256),
+            (vec![0xFF, 0xFE], 256, 255),
+        ];
+
+        for (header, rows, cols) in test_cases {
+            for extra in [0, 1, 49, 1000] {
+                let mut bytes = header.clone();
+                bytes.resize(header.len() + extra, 0x02);
+
+                match VerificationMatrix::try_from_bytes(&bytes) {
+                    Err(DecodeError::LengthMismatch { expected, got }) => {
+                        assert_eq!(expected, VerificationMatrix::byte_size(rows, cols));
+                        assert_eq!(got, bytes.len());
+                    }
+                    Err(DecodeError::SizeOverflow) if cfg!(target_pointer_width = "32") => (),
+                    res => panic!("unexpected result: {:?}", res),
+                }
+
+                assert!(VerificationMatrix::from_bytes(&bytes).is_none());
+                assert!(VerificationMatrix::from_bytes_lossy(&bytes).is_none());
+                assert!(VerificationMatrix::try_from_bytes_bounded(&bytes, 65535, 65535).is_err());
+                assert!(VerificationMatrix::from_bytes_fixed(&bytes, rows, cols).is_none());
+                assert!(vss::VerificationMatrixView::<Group>::new(&bytes).is_err());
+
+                let res = VerificationMatrix::read_from(&mut bytes.as_slice());
+                assert!(res.is_err());
+            }
+        }
+
+        // Uncompressed elements.
+        let bytes = vec![0xFF, 0xFF, 0x02, 0xFF, 0xFF, 0xFF, 0xFF, 0x04];
+        assert!(VerificationMatrix::from_bytes_with(&bytes, PointEncoding::Uncompressed).is_none());
+    }
+
+    #[test]
+    fn test_to_bytes_versioned() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let size = VerificationMatrix::element_byte_size();
+
+        // Legacy format is the default for small degrees.
+        let legacy = vm
+            .to_bytes_versioned(Format::Legacy)
+            .expect("dimensions should fit");
+        assert_eq!(legacy, vm.to_bytes());
+        assert_eq!(legacy[..2], [2, 3]);
+
+        // Extended format can be requested explicitly.
+        let extended = vm
+            .to_bytes_versioned(Format::Extended)
+            .expect("dimensions should fit");
+        assert_eq!(extended[..7], [0xFF, 0xFF, 0x01, 2, 0, 3, 0]);
+        assert_eq!(extended[7..], legacy[2..]);
+        assert_eq!(extended.len(), 7 + 3 * 4 * size);
+
+        let restored =
+            VerificationMatrix::try_from_bytes(&extended).expect("decoding should succeed");
+        assert_eq!(restored, vm);
+
+        // Unknown format tag or truncated extended header.
+        let mut corrupted = extended.clone();
+        corrupted[2] = 0x7F;
+        let res = VerificationMatrix::try_from_bytes(&corrupted);
+        assert_eq!(res.unwrap_err(), DecodeError::UnsupportedFormat(0x7F));
+
+        let res = VerificationMatrix::try_from_bytes(&extended[..6]);
+        assert_eq!(res.unwrap_err(), DecodeError::TooShort);
+
+        // Legacy header of a 256x256 matrix, which starts like an extended one.
+        let bytes = vec![0xFF; VerificationMatrix::byte_size(256, 256)];
+        let res = VerificationMatrix::try_from_bytes(&bytes);
+        assert_eq!(
+            res.unwrap_err(),
+            DecodeError::InvalidElement { row: 0, col: 0 }
+        );
+    }
+
+    #[test]
+    fn test_serialization_large_degrees() {
+        let g = Group::generator();
+
+        for (rows, cols) in [(301, 2), (2, 301)] {
+            let m = (0..rows)
+                .map(|i| (0..cols).map(|j| g * scalar(i * cols + j)).collect())
+                .collect();
+            let vm = VerificationMatrix::new(m).expect("matrix should be valid");
+
+            // Legacy format cannot encode degrees above 255.
+            let res = vm.to_bytes_versioned(Format::Legacy);
+            assert_eq!(
+                res.unwrap_err().to_string(),
+                Error::DimensionsTooLarge.to_string()
+            );
+
+            let bytes = vm.to_bytes();
+            let expected = vm
+                .to_bytes_versioned(Format::Extended)
+                .expect("dimensions should fit");
+            assert_eq!(bytes, expected);
+            assert_eq!(
+                bytes.len(),
+                VerificationMatrix::byte_size(rows as usize, cols as usize)
+            );
+
+            let deg_x = (rows as u16 - 1).to_le_bytes();
+            let deg_y = (cols as u16 - 1).to_le_bytes();
+            assert_eq!(bytes[..3], [0xFF, 0xFF, 0x01]);
+            assert_eq!(bytes[3..5], deg_x);
+            assert_eq!(bytes[5..7], deg_y);
+
+            let restored = VerificationMatrix::from_bytes(&bytes);
+            assert_eq!(restored.as_ref(), Some(&vm));
+
+            let restored =
+                VerificationMatrix::from_bytes_fixed(&bytes, rows as usize, cols as usize);
+            assert_eq!(restored.as_ref(), Some(&vm));
+
+            let (pm, missing) = VerificationMatrix::from_bytes_lossy(&bytes)
+                .expect("deserialization should succeed");
+            assert!(missing.is_empty());
+            assert_eq!(pm.finalize().expect("matrix should be complete"), vm);
+        }
+    }
+
+    #[test]
+    fn test_to_bytes_with() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        // Compressed encoding matches the default one.
+        let compressed = vm.to_bytes_with(PointEncoding::Compressed);
+        assert_eq!(compressed, vm.to_bytes());
+        assert_eq!(
+            compressed.len(),
+            VerificationMatrix::byte_size_with(PointEncoding::Compressed, 3, 4)
+        );
+        let restored = VerificationMatrix::from_bytes_with(&compressed, PointEncoding::Compressed);
+        assert_eq!(restored.as_ref(), Some(&vm));
+
+        // Uncompressed encoding uses the extended format.
+        let uncompressed = vm.to_bytes_with(PointEncoding::Uncompressed);
+        assert_eq!(uncompressed[..7], [0xFF, 0xFF, 0x02, 2, 0, 3, 0]);
+        assert_eq!(uncompressed.len(), 7 + 3 * 4 * 97);
+        assert_eq!(
+            uncompressed.len(),
+            VerificationMatrix::byte_size_with(PointEncoding::Uncompressed, 3, 4)
+        );
+        let restored =
+            VerificationMatrix::from_bytes_with(&uncompressed, PointEncoding::Uncompressed);
+        assert_eq!(restored.as_ref(), Some(&vm));
+
+        // Encoding must match the header.
+        let res = VerificationMatrix::try_from_bytes(&uncompressed);
+        assert_eq!(res.unwrap_err(), DecodeError::UnexpectedEncoding);
+        assert!(
+            VerificationMatrix::from_bytes_with(&compressed, PointEncoding::Uncompressed).is_none()
+        );
+
+        let extended = vm
+            .to_bytes_versioned(Format::Extended)
+            .expect("dimensions should fit");
+        assert!(
+            VerificationMatrix::from_bytes_with(&extended, PointEncoding::Uncompressed).is_none()
+        );
+
+        // Invalid element or length.
+        let mut corrupted = uncompressed.clone();
+        corrupted[7 + 5 * 97 + 96] ^= 1;
+        assert!(
+            VerificationMatrix::from_bytes_with(&corrupted, PointEncoding::Uncompressed).is_none()
+        );
+        let truncated = &uncompressed[..uncompressed.len() - 1];
+        assert!(
+            VerificationMatrix::from_bytes_with(truncated, PointEncoding::Uncompressed).is_none()
+        );
+    }
+
+    /// A reader that returns at most `chunk` bytes per read.
+    struct ChunkedReader<'a> {
+        data: &'a [u8],
+        chunk: usize,
+    }
+
+    impl std::io::Read for ChunkedReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.chunk).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
     }
 
     #[test]
-    fn test_dimensions() {
+    fn test_read_from_write_to() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
-        let bp = BivariatePolynomial::random(5, 10, &mut rng);
+        let g = Group::generator();
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let small = VerificationMatrix::from(&bp);
+        let large = VerificationMatrix::new(vec![vec![g; 2]; 301]).expect("matrix should be valid");
 
-        let vm = VerificationMatrix::from(&bp);
-        assert_eq!((6, 11), vm.dimensions());
+        for vm in [small, large] {
+            let mut bytes = Vec::new();
+            vm.write_to(&mut bytes).expect("writing should succeed");
+            assert_eq!(bytes, vm.to_bytes());
+
+            for chunk in [1, 3, 50, usize::MAX] {
+                let mut reader = ChunkedReader {
+                    data: &bytes,
+                    chunk,
+                };
+                let restored =
+                    VerificationMatrix::read_from(&mut reader).expect("reading should succeed");
+                assert_eq!(restored, vm);
+                assert!(reader.data.is_empty());
+            }
+        }
     }
 
     #[test]
-    fn test_element() {
-        let c = scalar(42);
-        let e = Group::GENERATOR * c;
+    fn test_legacy_max_dimensions() {
+        // The legacy header of a 256x256 matrix is the extended marker.
+        // The byte representations are built by hand, as encoding and
+        // comparing such large matrices is slow.
+        let g = Group::generator();
+        let mut elements = g.to_bytes().to_vec();
+        elements.resize(256 * 256 * VerificationMatrix::element_byte_size(), 0);
 
-        let mut bp = BivariatePolynomial::zero(2, 3);
-        assert!(bp.set_coefficient(1, 2, c));
+        let legacy = [&[0xFF, 0xFF], &elements[..]].concat();
+        let extended = [&[0xFF, 0xFF, 0x01, 0xFF, 0x00, 0xFF, 0x00], &elements[..]].concat();
+        assert_eq!(legacy.len(), VerificationMatrix::byte_size(256, 256));
 
-        let vm = VerificationMatrix::from(&bp);
-        assert_eq!(&e, vm.element(1, 2).unwrap());
+        for bytes in [legacy, extended] {
+            let mut reader = ChunkedReader {
+                data: &bytes,
+                chunk: 1000,
+            };
+            let restored = [
+                VerificationMatrix::try_from_bytes(&bytes).expect("decoding should succeed"),
+                VerificationMatrix::from_bytes(&bytes).expect("decoding should succeed"),
+                VerificationMatrix::read_from(&mut reader).expect("reading should succeed"),
+            ];
+            assert!(reader.data.is_empty());
+
+            for vm in restored {
+                assert_eq!(vm.dimensions(), (256, 256));
+                assert_eq!(vm.element(0, 0), Some(&g));
+                assert_eq!(vm.element(255, 255), Some(&Group::identity()));
+            }
+        }
     }
 
     #[test]
-    fn test_verify() {
+    fn test_read_from_errors() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
-        let x2 = scalar(2);
-        let x3 = scalar(3);
-
         let bp = BivariatePolynomial::random(2, 3, &mut rng);
-        let s = bp.eval_x(&x2).eval(&x3);
         let vm = VerificationMatrix::from(&bp);
-        assert!(vm.verify(&x2, &x3, &s));
-        assert!(!vm.verify(&x3, &x2, &s));
+        let bytes = vm.to_bytes();
+        let size = VerificationMatrix::element_byte_size();
+
+        // Trailing data is left in the reader.
+        let mut data = bytes.clone();
+        data.extend_from_slice(&bytes);
+        let mut reader = ChunkedReader {
+            data: &data,
+            chunk: 7,
+        };
+        for _ in 0..2 {
+            let restored =
+                VerificationMatrix::read_from(&mut reader).expect("reading should succeed");
+            assert_eq!(restored, vm);
+        }
+        let res = VerificationMatrix::read_from(&mut reader);
+        assert_eq!(res.unwrap_err(), DecodeError::TooShort);
+
+        // Truncated input.
+        for len in [0, 1, 2, bytes.len() - 1] {
+            let mut reader = ChunkedReader {
+                data: &bytes[..len],
+                chunk: 5,
+            };
+            let res = VerificationMatrix::read_from(&mut reader);
+            assert_eq!(res.unwrap_err(), DecodeError::TooShort);
+        }
+
+        // Reading stops at the first invalid element.
+        let mut corrupted = bytes.clone();
+        corrupted[2 + (4 + 2) * size] = 0xFF; // m_{1,2}
+        corrupted[2 + (8 + 1) * size] = 0xFF; // m_{2,1}
+        let mut reader = ChunkedReader {
+            data: &corrupted,
+            chunk: 11,
+        };
+        let res = VerificationMatrix::read_from(&mut reader);
+        assert_eq!(
+            res.unwrap_err(),
+            DecodeError::InvalidElement { row: 1, col: 2 }
+        );
+        assert_eq!(reader.data.len(), corrupted.len() - 2 - 7 * size);
+
+        // Unsupported format is read as a legacy 256x256 matrix.
+        let mut extended = vm
+            .to_bytes_versioned(Format::Extended)
+            .expect("dimensions should fit");
+        extended[2] = 0x7F;
+        let mut reader = ChunkedReader {
+            data: &extended,
+            chunk: 1,
+        };
+        let res = VerificationMatrix::read_from(&mut reader);
+        assert_eq!(
+            res.unwrap_err(),
+            DecodeError::InvalidElement { row: 0, col: 0 }
+        );
     }
 
     #[test]
-    fn test_verification_polynomial_for_x() {
+    fn test_read_from_io_error() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
-        let x2 = scalar(2);
-        let x3 = scalar(3);
-
         let bp = BivariatePolynomial::random(2, 3, &mut rng);
         let vm = VerificationMatrix::from(&bp);
-        let p = bp.eval_y(&x2);
+        let bytes = vm.to_bytes();
+        let size = VerificationMatrix::element_byte_size();
 
-        let vv = vm.verification_vector_for_x(&x2);
-        assert!(vv.is_from(&p));
+        let read = |bytes: &[u8]| -> std::io::Result<VerificationMatrix> {
+            Ok(VerificationMatrix::read_from(&mut &bytes[..])?)
+        };
+        assert_eq!(read(&bytes).expect("reading should succeed"), vm);
 
-        let vv = vm.verification_vector_for_x(&x3);
-        assert!(!vv.is_from(&p));
+        // Short read.
+        let err = read(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        // Invalid element.
+        let mut corrupted = bytes.clone();
+        corrupted[2 + 3 * size] = 0xFF;
+        let err = read(&corrupted).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        // Other I/O errors keep their kind.
+        let err = std::io::Error::from(DecodeError::Io(std::io::ErrorKind::BrokenPipe));
+        assert_eq!(err.kind(), std::io::ErrorKind::BrokenPipe);
     }
 
     #[test]
-    fn test_verification_polynomial_for_y() {
+    fn test_digest() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
-        let x2 = scalar(2);
-        let x3 = scalar(3);
-
         let bp = BivariatePolynomial::random(2, 3, &mut rng);
         let vm = VerificationMatrix::from(&bp);
-        let p = bp.eval_x(&x2);
+        let digest = vm.digest::<Sha3_256>();
 
-        let vv = vm.verification_vector_for_y(&x2);
-        assert!(vv.is_from(&p));
+        // Equal matrices.
+        assert_eq!(vm.clone().digest::<Sha3_256>(), digest);
+        let restored = VerificationMatrix::from_bytes(&vm.to_bytes()).unwrap();
+        assert_eq!(restored.digest::<Sha3_256>(), digest);
 
-        let vv = vm.verification_vector_for_y(&x3);
-        assert!(!vv.is_from(&p));
+        // Different element.
+        let mut other = vm.clone();
+        *other.element_mut(1, 2).unwrap() += Group::generator();
+        assert_ne!(other.digest::<Sha3_256>(), digest);
+
+        // Different dimensions, same elements.
+        let m = vm.m.clone();
+        let other = VerificationMatrix::new(vec![m]).unwrap();
+        assert_ne!(other.digest::<Sha3_256>(), digest);
+
+        let other = vm.transpose();
+        assert_ne!(other.digest::<Sha3_256>(), digest);
     }
 
     #[test]
-    fn test_verify_x() {
-        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
-        let x2 = scalar(2);
-
-        // Asymmetric bivariate polynomial.
-        let bp = BivariatePolynomial::random(2, 3, &mut rng);
-        let p = bp.eval_x(&x2);
-        let vm = VerificationMatrix::from(&bp);
-        assert!(vm.verify_x(&x2, &p));
-        assert!(!vm.verify_y(&x2, &p)); // Invalid degree.
+    fn test_digest_known_answers() {
+        // SHA3-256 digests of P-384 verification matrices for bivariate
+        // polynomials with the given coefficients.
+        let test_cases = vec![
+            (
+                vec![vec![0]],
+                "7d25a1ea9cb3a21459c69522b781d0eba20caeb5cecd0aed16fde9a7fa62b236",
+            ),
+            (
+                vec![vec![1, 2, 3]],
+                "b0fce9b9fa71c6a68a6a487bc07d1eb518da165021103beebfd96cb51696b051",
+            ),
+            (
+                vec![vec![1, 2], vec![3, 4], vec![5, 6]],
+                "36fb14393bd255e72a170a609aaad7b69ae805cc481bfe9973fa963627f6247f",
+            ),
+        ];
 
-        // Symmetric bivariate polynomial.
-        let bp = BivariatePolynomial::random(2, 2, &mut rng);
-        let p = bp.eval_x(&x2);
-        let vm = VerificationMatrix::from(&bp);
-        assert!(vm.verify_x(&x2, &p));
-        assert!(!vm.verify_y(&x2, &p)); // Valid degree, but verification failed.
+        for (b, expected) in test_cases {
+            let b = b.iter().map(|bi| scalars(bi)).collect();
+            let bp = BivariatePolynomial::with_coefficients(b);
+            let vm = VerificationMatrix::from(&bp);
+            let digest = vm.digest::<Sha3_256>();
+            let digest: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+            assert_eq!(digest, expected);
+        }
     }
 
     #[test]
-    fn test_verify_y() {
+    fn test_hash() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
-        let y2 = scalar(2);
+        let vm1 = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        let vm2 = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        let vm3 = VerificationMatrix::new(vec![vm1.m.clone()]).unwrap();
 
-        // Asymmetric bivariate polynomial.
-        let bp = BivariatePolynomial::random(2, 3, &mut rng);
-        let p = bp.eval_y(&y2);
-        let vm = VerificationMatrix::from(&bp);
-        assert!(!vm.verify_x(&y2, &p)); // Invalid degree.
-        assert!(vm.verify_y(&y2, &p));
+        let mut set = HashSet::new();
+        assert!(set.insert(vm1.clone()));
+        assert!(set.insert(vm2.clone()));
+        assert!(set.insert(vm3.clone()));
 
-        // Symmetric bivariate polynomial.
-        let bp = BivariatePolynomial::random(2, 2, &mut rng);
-        let p = bp.eval_y(&y2);
-        let vm = VerificationMatrix::from(&bp);
-        assert!(!vm.verify_x(&y2, &p)); // Valid degree, but verification failed.
-        assert!(vm.verify_y(&y2, &p));
+        // Equal matrices are deduplicated.
+        assert!(!set.insert(vm1.clone()));
+        let restored = VerificationMatrix::from_bytes(&vm2.to_bytes()).unwrap();
+        assert!(!set.insert(restored));
+        assert_eq!(set.len(), 3);
+
+        assert!(set.contains(&vm1));
+        assert!(!set.contains(&vm1.transpose()));
     }
 
     #[test]
-    fn test_serialization() {
+    fn test_ct_eq() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
-        let bp = BivariatePolynomial::random(2, 3, &mut rng);
-        let vm = VerificationMatrix::from(&bp);
-        let restored =
-            VerificationMatrix::from_bytes(&vm.to_bytes()).expect("deserialization should succeed");
+        let (_, vm1) = VerificationMatrix::random(2, 3, &mut rng);
+        let (_, vm2) = VerificationMatrix::random(2, 3, &mut rng);
 
-        assert_eq!(vm, restored);
+        assert!(bool::from(vm1.ct_eq(&vm1.clone())));
+        let restored = VerificationMatrix::from_bytes(&vm1.to_bytes()).unwrap();
+        assert!(bool::from(vm1.ct_eq(&restored)));
+        assert!(!bool::from(vm1.ct_eq(&vm2)));
+
+        // Single differing element, at any position.
+        for k in [0, 5, 11] {
+            let mut other = vm1.clone();
+            other.m[k] += Group::generator();
+            assert!(!bool::from(vm1.ct_eq(&other)));
+            assert!(!bool::from(other.ct_eq(&vm1)));
+        }
+
+        // Different dimensions.
+        let extended = vm1.extend_zero(3, 3).unwrap();
+        assert!(!bool::from(vm1.ct_eq(&extended)));
+        let reshaped = VerificationMatrix::new(vec![vm1.m.clone()]).unwrap();
+        assert!(!bool::from(vm1.ct_eq(&reshaped)));
+
+        // Agrees with the derived equality.
+        for (a, b) in [(&vm1, &vm1), (&vm1, &vm2), (&vm1, &extended)] {
+            assert_eq!(bool::from(a.ct_eq(b)), a == b);
+        }
     }
 
     #[test]
@@ -592,6 +4415,12 @@ mod tests {
     fn test_byte_size() {
         let size = VerificationMatrix::byte_size(2, 3);
         assert_eq!(size, 2 + 2 * 3 * 49);
+
+        let size = VerificationMatrix::byte_size(300, 3);
+        assert_eq!(size, 7 + 300 * 3 * 49);
+
+        let size = VerificationMatrix::byte_size_versioned(Format::Extended, 2, 3);
+        assert_eq!(size, 7 + 2 * 3 * 49);
     }
 
     #[test]
@@ -658,4 +4487,354 @@ mod tests {
             assert_eq!(sum, vm3);
         }
     }
+
+    #[test]
+    pub fn test_sub() {
+        let test_cases = vec![
+            // Same size.
+            (
+                vec![scalars(&[0, 1, 2]), scalars(&[3, 4, 5])],
+                vec![scalars(&[1, 3, 5]), scalars(&[0, 2, 4])],
+                vec![scalars(&[-1, -2, -3]), scalars(&[3, 2, 1])],
+            ),
+            // LHS smaller.
+            (
+                vec![scalars(&[0, 1]), scalars(&[3, 4])],
+                vec![scalars(&[1, 3, 5]), scalars(&[0, 2, 4])],
+                vec![scalars(&[-1, -2, -5]), scalars(&[3, 2, -4])],
+            ),
+            // RHS smaller.
+            (
+                vec![scalars(&[0, 1, 2]), scalars(&[3, 4, 5])],
+                vec![scalars(&[1, 3]), scalars(&[0, 2])],
+                vec![scalars(&[-1, -2, 2]), scalars(&[3, 2, 5])],
+            ),
+            // Mixed size.
+            (
+                vec![scalars(&[1, 2, 3, 4]), scalars(&[5, 6, 7, 8])],
+                vec![scalars(&[1, 2]), scalars(&[3, 4]), scalars(&[5, 6])],
+                vec![
+                    scalars(&[0, 0, 3, 4]),
+                    scalars(&[2, 2, 7, 8]),
+                    scalars(&[-5, -6, 0, 0]),
+                ],
+            ),
+        ];
+
+        for (c1, c2, c3) in test_cases {
+            let bp1 = BivariatePolynomial::with_coefficients(c1);
+            let bp2 = BivariatePolynomial::with_coefficients(c2);
+            let bp3 = BivariatePolynomial::with_coefficients(c3);
+            let vm1 = VerificationMatrix::from(&bp1);
+            let vm2 = VerificationMatrix::from(&bp2);
+            let vm3 = VerificationMatrix::from(&bp3);
+
+            // Test sub.
+            let diff = vm1.clone() - vm2.clone();
+            assert_eq!(diff, vm3);
+
+            let diff = vm1.clone() - &vm2.clone();
+            assert_eq!(diff, vm3);
+
+            let diff = &vm1.clone() - vm2.clone();
+            assert_eq!(diff, vm3);
+
+            let diff = &vm1.clone() - &vm2.clone();
+            assert_eq!(diff, vm3);
+
+            // Test sub assign.
+            let mut diff = vm1.clone();
+            diff -= vm2.clone();
+            assert_eq!(diff, vm3);
+
+            let mut diff = vm1.clone();
+            diff -= &vm2.clone();
+            assert_eq!(diff, vm3);
+
+            // Test homomorphism.
+            let diff = VerificationMatrix::from(&(&bp1 - &bp2));
+            assert_eq!(diff, vm3);
+        }
+
+        // Test round trip.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let vm1 = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        let vm2 = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        let vm3 = VerificationMatrix::from(&BivariatePolynomial::random(1, 2, &mut rng));
+        assert_eq!(&vm1 + &vm2 - &vm2, vm1);
+        assert_eq!(&vm1 + &vm3 - &vm3, vm1);
+
+        // Test round trip with padding.
+        let vm = &vm3 + &vm1 - &vm1;
+        assert_eq!(vm.dimensions(), (3, 4));
+        assert_eq!(vm, vm3.extend_zero(2, 3).expect("degrees should be valid"));
+        assert_eq!(vm.truncate(1, 2).expect("degrees should be valid"), vm3);
+    }
+
+    #[test]
+    pub fn test_sum() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bps = [
+            BivariatePolynomial::random(2, 3, &mut rng),
+            BivariatePolynomial::random(1, 4, &mut rng),
+            BivariatePolynomial::random(3, 0, &mut rng),
+        ];
+        let vms: Vec<_> = bps.iter().map(VerificationMatrix::from).collect();
+
+        let mut expected = vms[0].clone();
+        for vm in &vms[1..] {
+            expected += vm;
+        }
+        assert_eq!(expected.dimensions(), (4, 5));
+
+        // Test sum.
+        let sum: VerificationMatrix = vms.iter().sum();
+        assert_eq!(sum, expected);
+
+        let sum: VerificationMatrix = vms.clone().into_iter().sum();
+        assert_eq!(sum, expected);
+
+        // Test verification of the summed evaluations.
+        let x = scalar(2);
+        let y = scalar(3);
+        let v = bps.iter().map(|bp| bp.eval(&x, &y)).sum();
+        assert!(sum.verify(&x, &y, &v));
+
+        // Test single matrix.
+        let sum: VerificationMatrix = vms[..1].iter().sum();
+        assert_eq!(sum, vms[0]);
+
+        // Test empty iterator.
+        let sum: VerificationMatrix = Vec::<VerificationMatrix>::new().iter().sum();
+        let zero = VerificationMatrix::from(&BivariatePolynomial::zero(0, 0));
+        assert_eq!(sum, zero);
+        assert!(sum.is_zero_hole());
+    }
+
+    #[test]
+    pub fn test_neg() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let zero = VerificationMatrix::from(&BivariatePolynomial::zero(2, 3));
+
+        let b =
+            bp.b.iter()
+                .map(|bi| bi.iter().map(|bij| -*bij).collect())
+                .collect();
+        let neg_vm = VerificationMatrix::from(&BivariatePolynomial::with_coefficients(b));
+
+        assert_eq!(-&vm, neg_vm);
+        assert_eq!(-vm.clone(), neg_vm);
+        assert_eq!(-(-&vm), vm);
+        assert_eq!(&vm + &neg_vm, zero);
+        assert_eq!(&zero - &vm, neg_vm);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_scalar_mul() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let s = scalar(7);
+
+        let b =
+            bp.b.iter()
+                .map(|bi| bi.iter().map(|bij| bij * &s).collect())
+                .collect();
+        let scaled_bp = BivariatePolynomial::with_coefficients(b);
+        assert!(&bp * s == scaled_bp);
+
+        let vm = VerificationMatrix::from(&bp);
+        let scaled_vm = VerificationMatrix::from(&scaled_bp);
+
+        // Test mul.
+        assert_eq!(vm.clone() * s, scaled_vm);
+        assert_eq!(vm.clone() * &s, scaled_vm);
+        assert_eq!(&vm * s, scaled_vm);
+        assert_eq!(&vm * &s, scaled_vm);
+
+        // Test mul assign.
+        let mut prod = vm.clone();
+        prod *= s;
+        assert_eq!(prod, scaled_vm);
+        assert_eq!(prod.dimensions(), vm.dimensions());
+
+        let mut prod = vm.clone();
+        prod *= &s;
+        assert_eq!(prod, scaled_vm);
+
+        // Test zero.
+        let zero = VerificationMatrix::from(&BivariatePolynomial::zero(2, 3));
+        assert_eq!(&vm * &scalar(0), zero);
+        assert_eq!(vm.clone() * scalar(0), zero);
+
+        // Test verification of scaled evaluations.
+        for (x, y) in [(scalar(2), scalar(3)), (scalar(0), scalar(5))] {
+            let v = bp.eval(&x, &y);
+            assert!(vm.verify(&x, &y, &v));
+            assert!((&vm * &s).verify(&x, &y, &(s * v)));
+            assert!((vm.clone() * s).verify(&x, &y, &(s * v)));
+            assert!(!(&vm * &s).verify(&x, &y, &v));
+        }
+    }
+
+    fn bench_verify(b: &mut Bencher, deg_x: u8, deg_y: u8, naive: bool) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let x = scalar(2);
+        let y = scalar(3);
+        let s = bp.eval(&x, &y);
+
+        b.iter(|| match naive {
+            true => vm.verify_naive(&x, &y, &s),
+            false => vm.verify(&x, &y, &s),
+        });
+    }
+
+    #[bench]
+    fn bench_sum_50(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let distinct: Vec<_> = (0..5)
+            .map(|k| {
+                let bp = BivariatePolynomial::random(10 + k, 20, &mut rng);
+                VerificationMatrix::from(&bp)
+            })
+            .collect();
+        let vms: Vec<_> = distinct.iter().cycle().take(50).collect();
+
+        b.iter(|| vms.iter().copied().sum::<VerificationMatrix>());
+    }
+
+    fn bench_verification_vectors_for_x(b: &mut Bencher, batch: bool) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(20, 40, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let ys: Vec<_> = (1..=30).map(scalar).collect();
+
+        b.iter(|| match batch {
+            true => vm.verification_vectors_for_x(&ys),
+            false => ys.iter().map(|y| vm.verification_vector_for_x(y)).collect(),
+        });
+    }
+
+    #[bench]
+    fn bench_verification_vectors_for_x_30_shareholders(b: &mut Bencher) {
+        bench_verification_vectors_for_x(b, true)
+    }
+
+    #[bench]
+    fn bench_verification_vector_for_x_30_shareholders(b: &mut Bencher) {
+        bench_verification_vectors_for_x(b, false)
+    }
+
+    #[bench]
+    fn bench_verify_with_context_30_60(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(29, 59, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let x = scalar(2);
+        let y = scalar(3);
+        let s = bp.eval(&x, &y);
+        let ctx = VerificationContext::new(x, y, 29, 59);
+
+        b.iter(|| vm.verify_with_context(&ctx, &s));
+    }
+
+    #[bench]
+    fn bench_verify_30_60(b: &mut Bencher) {
+        bench_verify(b, 29, 59, false)
+    }
+
+    #[bench]
+    fn bench_verify_naive_30_60(b: &mut Bencher) {
+        bench_verify(b, 29, 59, true)
+    }
+
+    fn bench_from(b: &mut Bencher, deg_x: u8, deg_y: u8, naive: bool) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+
+        b.iter(|| match naive {
+            true => VerificationMatrix::from_polynomial_with(&bp, |bij| Group::generator() * bij),
+            false => VerificationMatrix::from(&bp),
+        });
+    }
+
+    #[bench]
+    fn bench_from_30_60(b: &mut Bencher) {
+        bench_from(b, 29, 59, false)
+    }
+
+    #[bench]
+    fn bench_from_naive_30_60(b: &mut Bencher) {
+        bench_from(b, 29, 59, true)
+    }
+
+    #[bench]
+    fn bench_from_21_21(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(20, 20, &mut rng);
+
+        b.iter(|| VerificationMatrix::from(&bp));
+    }
+
+    fn bench_verify_x(b: &mut Bencher, deg_x: u8, deg_y: u8, naive: bool) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let x = scalar(2);
+        let p = bp.eval_x(&x);
+
+        b.iter(|| match naive {
+            true => vm.verify_x_with(&x, &p, |aj| Group::generator() * aj),
+            false => vm.verify_x(&x, &p),
+        });
+    }
+
+    #[bench]
+    fn bench_verify_x_30_60(b: &mut Bencher) {
+        bench_verify_x(b, 29, 59, false)
+    }
+
+    #[bench]
+    fn bench_verify_x_naive_30_60(b: &mut Bencher) {
+        bench_verify_x(b, 29, 59, true)
+    }
+
+    #[bench]
+    fn bench_verify_x_with_table_30_60(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(29, 59, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let table = GeneratorTable::new();
+        let x = scalar(2);
+        let p = bp.eval_x(&x);
+
+        b.iter(|| vm.verify_x_with_table(&x, &p, &table));
+    }
+
+    fn bench_verify_y(b: &mut Bencher, deg_x: u8, deg_y: u8, naive: bool) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let y = scalar(2);
+        let p = bp.eval_y(&y);
+
+        b.iter(|| match naive {
+            true => vm.verify_y_with(&y, &p, |ai| Group::generator() * ai),
+            false => vm.verify_y(&y, &p),
+        });
+    }
+
+    #[bench]
+    fn bench_verify_y_30_60(b: &mut Bencher) {
+        bench_verify_y(b, 29, 59, false)
+    }
+
+    #[bench]
+    fn bench_verify_y_naive_30_60(b: &mut Bencher) {
+        bench_verify_y(b, 29, 59, true)
+    }
 }
@@ -0,0 +1,433 @@
+//! Merkle commitments to the rows of verification matrices.
+
+use std::convert::TryInto;
+
+use group::{Group, GroupEncoding};
+use sha3::{Digest, Sha3_256};
+
+use super::{DecodeError, VerificationMatrix, VerificationVector};
+
+/// The size of a node of the Merkle tree.
+const HASH_SIZE: usize = 32;
+
+/// The maximum depth of the Merkle tree, which suffices for the maximum
+/// number of rows of a verification matrix.
+const MAX_DEPTH: usize = 16;
+
+/// The domain separation prefix of leaf nodes.
+const LEAF_PREFIX: u8 = 0x00;
+
+/// The domain separation prefix of inner nodes.
+const NODE_PREFIX: u8 = 0x01;
+
+/// The node standing in for missing leaves when the number of rows
+/// is not a power of two.
+///
+/// It is not the hash of any row, so no proof can open it.
+const EMPTY_LEAF: [u8; HASH_SIZE] = [0; HASH_SIZE];
+
+/// Merkle commitment to the rows of a verification matrix.
+///
+/// The leaves of the tree are the hashes of the rows of the matrix
+/// in their byte representation, i.e., the concatenation of the compressed
+/// elements, padded with empty leaves to the next power of two:
+/// ```text
+///     leaf_i = H(0x00 || M_{i,0} || ... || M_{i,deg_y})
+///     node   = H(0x01 || left || right)
+/// ```
+/// where `H` is SHA3-256.
+///
+/// The root can be broadcast in place of the whole matrix, while each
+/// recipient receives a [`RowProof`] for the rows it needs.
+#[derive(Debug, Clone)]
+pub struct MatrixCommitment {
+    /// The byte representations of the rows of the matrix.
+    rows: Vec<Vec<u8>>,
+    /// The levels of the tree, from the leaves up to the root.
+    levels: Vec<Vec<[u8; HASH_SIZE]>>,
+}
+
+impl MatrixCommitment {
+    /// Builds the Merkle tree over the rows of the given verification matrix.
+    pub fn new<G>(vm: &VerificationMatrix<G>) -> Self
+    where
+        G: Group + GroupEncoding,
+    {
+        let rows: Vec<Vec<u8>> = vm
+            .rows_iter()
+            .map(|mi| {
+                mi.iter()
+                    .flat_map(|mij| mij.to_bytes().as_ref().to_vec())
+                    .collect()
+            })
+            .collect();
+
+        let mut leaves: Vec<_> = rows.iter().map(|row| hash_leaf(row)).collect();
+        leaves.resize(rows.len().next_power_of_two(), EMPTY_LEAF);
+
+        let mut levels = vec![leaves];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| hash_node(&pair[0], &pair[1]))
+                .collect();
+            levels.push(level);
+        }
+
+        Self { rows, levels }
+    }
+
+    /// Returns the root of the Merkle tree.
+    pub fn root(&self) -> [u8; HASH_SIZE] {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns the number of committed rows.
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the inclusion proof of the `i`-th row, or `None` if the row
+    /// doesn't exist.
+    pub fn prove(&self, i: usize) -> Option<RowProof> {
+        let row_bytes = self.rows.get(i)?.clone();
+
+        let mut path = Vec::with_capacity(self.levels.len() - 1);
+        let mut k = i;
+        for level in &self.levels[..self.levels.len() - 1] {
+            path.push(level[k ^ 1]);
+            k >>= 1;
+        }
+
+        Some(RowProof {
+            row_index: i,
+            row_bytes,
+            path,
+        })
+    }
+}
+
+/// Inclusion proof of a row of a verification matrix in a [`MatrixCommitment`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowProof {
+    /// The index of the row.
+    row_index: usize,
+    /// The byte representation of the row.
+    row_bytes: Vec<u8>,
+    /// The siblings of the nodes on the path from the leaf to the root.
+    path: Vec<[u8; HASH_SIZE]>,
+}
+
+impl RowProof {
+    /// Returns the index of the row.
+    pub fn row_index(&self) -> usize {
+        self.row_index
+    }
+
+    /// Returns the byte representation of the row.
+    pub fn row_bytes(&self) -> &[u8] {
+        &self.row_bytes
+    }
+
+    /// Returns the siblings of the nodes on the path from the leaf
+    /// to the root.
+    pub fn path(&self) -> &[[u8; HASH_SIZE]] {
+        &self.path
+    }
+
+    /// Verifies whether the row is included in the commitment with the given
+    /// root at the index of the proof.
+    pub fn verify(&self, root: &[u8; HASH_SIZE]) -> bool {
+        if self.path.len() > MAX_DEPTH || self.row_index >> self.path.len() != 0 {
+            return false;
+        }
+
+        let mut node = hash_leaf(&self.row_bytes);
+        for (level, sibling) in self.path.iter().enumerate() {
+            node = match (self.row_index >> level) & 1 {
+                0 => hash_node(&node, sibling),
+                _ => hash_node(sibling, &node),
+            };
+        }
+
+        node == *root
+    }
+
+    /// Verifies the proof against the given root, as [`Self::verify`] does,
+    /// and returns the verification vector of the row, i.e., of
+    /// the coefficients of `x^i` in `B(x,y)`.
+    ///
+    /// Returns `None` if the proof is invalid or the row fails to decode.
+    pub fn verify_row<G>(&self, root: &[u8; HASH_SIZE]) -> Option<VerificationVector<G>>
+    where
+        G: Group + GroupEncoding,
+    {
+        if !self.verify(root) {
+            return None;
+        }
+
+        let element_size = VerificationMatrix::<G>::element_byte_size();
+        if self.row_bytes.is_empty() || self.row_bytes.len() % element_size != 0 {
+            return None;
+        }
+
+        let v = self
+            .row_bytes
+            .chunks(element_size)
+            .map(|chunk| {
+                let mut repr: G::Repr = Default::default();
+                repr.as_mut().copy_from_slice(chunk);
+                Option::from(G::from_bytes(&repr))
+            })
+            .collect::<Option<Vec<G>>>()?;
+
+        Some(VerificationVector::new(v))
+    }
+
+    /// Returns the byte representation of the proof:
+    /// ```text
+    ///     row_index (4 bytes) || row_len (4 bytes) || row_bytes || depth (1 byte) || path
+    /// ```
+    /// where integers are little-endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(9 + self.row_bytes.len() + self.path.len() * HASH_SIZE);
+        bytes.extend_from_slice(&(self.row_index as u32).to_le_bytes());
+        bytes.extend_from_slice(&(self.row_bytes.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.row_bytes);
+        bytes.push(self.path.len() as u8);
+        for node in &self.path {
+            bytes.extend_from_slice(node);
+        }
+
+        bytes
+    }
+
+    /// Attempts to create a proof from its byte representation,
+    /// as produced by [`Self::to_bytes`].
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let (row_index, rest) = split_u32(bytes)?;
+        let (row_len, rest) = split_u32(rest)?;
+
+        if rest.len() < row_len {
+            return Err(DecodeError::TooShort);
+        }
+        let (row_bytes, rest) = rest.split_at(row_len);
+
+        let (&depth, rest) = rest.split_first().ok_or(DecodeError::TooShort)?;
+        let expected_len = depth as usize * HASH_SIZE;
+        if rest.len() < expected_len {
+            return Err(DecodeError::TooShort);
+        }
+        if rest.len() > expected_len {
+            return Err(DecodeError::TrailingBytes {
+                count: rest.len() - expected_len,
+            });
+        }
+
+        let path = rest
+            .chunks(HASH_SIZE)
+            .map(|chunk| chunk.try_into().expect("chunk should have the hash size"))
+            .collect();
+
+        Ok(Self {
+            row_index,
+            row_bytes: row_bytes.to_vec(),
+            path,
+        })
+    }
+}
+
+/// Splits a little-endian 32-bit integer off the front of the given bytes.
+fn split_u32(bytes: &[u8]) -> Result<(usize, &[u8]), DecodeError> {
+    if bytes.len() < 4 {
+        return Err(DecodeError::TooShort);
+    }
+    let (n, rest) = bytes.split_at(4);
+    let n = u32::from_le_bytes(n.try_into().expect("slice should have 4 bytes"));
+
+    Ok((n as usize, rest))
+}
+
+/// Returns the hash of a leaf holding the given row.
+fn hash_leaf(row: &[u8]) -> [u8; HASH_SIZE] {
+    let mut hasher = Sha3_256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(row);
+    hasher.finalize().into()
+}
+
+/// Returns the hash of an inner node with the given children.
+fn hash_node(left: &[u8; HASH_SIZE], right: &[u8; HASH_SIZE]) -> [u8; HASH_SIZE] {
+    let mut hasher = Sha3_256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        poly,
+        vss::{self, DecodeError},
+    };
+
+    use super::{MatrixCommitment, RowProof, EMPTY_LEAF};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type Polynomial = poly::Polynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    #[test]
+    fn test_commitment() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(15, 15, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let mc = MatrixCommitment::new(&vm);
+        assert_eq!(mc.rows(), 16);
+        assert_eq!(MatrixCommitment::new(&vm).root(), mc.root());
+
+        // Any change to the matrix changes the root.
+        let mut other = vm.clone();
+        *other.element_mut(7, 3).unwrap() += Group::generator();
+        assert_ne!(MatrixCommitment::new(&other).root(), mc.root());
+        assert_ne!(MatrixCommitment::new(&vm.transpose()).root(), mc.root());
+
+        for i in 0..16 {
+            let proof = mc.prove(i).expect("row should exist");
+            assert_eq!(proof.row_index(), i);
+            assert_eq!(proof.path().len(), 4);
+            assert!(proof.verify(&mc.root()));
+
+            let row = proof
+                .verify_row::<Group>(&mc.root())
+                .expect("proof should be valid");
+            assert_eq!(Some(&row), vm.row(i).as_ref());
+
+            // The row verifies the coefficients of x^i in B(x,y).
+            let a = (0..16).map(|j| *bp.coefficient(i, j).unwrap()).collect();
+            let p = Polynomial::with_coefficients(a);
+            let y = PrimeField::random(&mut rng);
+            assert!(row.verify(&y, &p.eval(&y)));
+            assert!(!row.verify(&y, &(p.eval(&y) + PrimeField::ONE)));
+        }
+        assert!(mc.prove(16).is_none());
+    }
+
+    #[test]
+    fn test_commitment_padding() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for deg_x in [0, 1, 2, 4, 6] {
+            let vm = VerificationMatrix::from(&BivariatePolynomial::random(deg_x, 3, &mut rng));
+            let mc = MatrixCommitment::new(&vm);
+            let rows = deg_x as usize + 1;
+            let depth = rows.next_power_of_two().trailing_zeros() as usize;
+
+            for i in 0..rows {
+                let proof = mc.prove(i).expect("row should exist");
+                assert_eq!(proof.path().len(), depth);
+                assert_eq!(proof.verify_row::<Group>(&mc.root()), vm.row(i));
+            }
+            assert!(mc.prove(rows).is_none());
+        }
+
+        // Padding leaves cannot be opened.
+        let vm = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        let mc = MatrixCommitment::new(&vm);
+        let mut proof = mc.prove(2).expect("row should exist");
+        assert_eq!(proof.path[0], EMPTY_LEAF);
+        proof.row_index = 3;
+        assert!(!proof.verify(&mc.root()));
+    }
+
+    #[test]
+    fn test_proof_rejected() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let vm = VerificationMatrix::from(&BivariatePolynomial::random(15, 15, &mut rng));
+        let mc = MatrixCommitment::new(&vm);
+        let root = mc.root();
+        let proof = mc.prove(5).expect("row should exist");
+
+        // Wrong indices.
+        for row_index in [4, 6, 13, 16, 21, usize::MAX] {
+            let mut wrong = proof.clone();
+            wrong.row_index = row_index;
+            assert!(!wrong.verify(&root));
+            assert!(wrong.verify_row::<Group>(&root).is_none());
+        }
+
+        // Rows of other indices.
+        let mut wrong = proof.clone();
+        wrong.row_bytes = mc.prove(4).unwrap().row_bytes;
+        assert!(!wrong.verify(&root));
+
+        // Tampered rows.
+        for k in [0, 48, proof.row_bytes.len() - 1] {
+            let mut tampered = proof.clone();
+            tampered.row_bytes[k] ^= 1;
+            assert!(!tampered.verify(&root));
+        }
+        let mut tampered = proof.clone();
+        tampered.row_bytes.pop();
+        assert!(!tampered.verify(&root));
+
+        // Tampered paths.
+        for level in 0..4 {
+            let mut tampered = proof.clone();
+            tampered.path[level][0] ^= 1;
+            assert!(!tampered.verify(&root));
+        }
+        let mut tampered = proof.clone();
+        tampered.path.pop();
+        assert!(!tampered.verify(&root));
+        let mut tampered = proof.clone();
+        tampered.path.push(root);
+        assert!(!tampered.verify(&root));
+
+        // Mismatched roots.
+        let other = VerificationMatrix::from(&BivariatePolynomial::random(15, 15, &mut rng));
+        assert!(!proof.verify(&MatrixCommitment::new(&other).root()));
+        let mut root = root;
+        root[31] ^= 1;
+        assert!(!proof.verify(&root));
+        assert!(proof.verify_row::<Group>(&root).is_none());
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let vm = VerificationMatrix::from(&BivariatePolynomial::random(15, 15, &mut rng));
+        let mc = MatrixCommitment::new(&vm);
+        let proof = mc.prove(9).expect("row should exist");
+
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), 4 + 4 + 16 * 49 + 1 + 4 * 32);
+
+        let restored = RowProof::try_from_bytes(&bytes).expect("decoding should succeed");
+        assert_eq!(restored, proof);
+        assert!(restored.verify(&mc.root()));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+
+        let test_cases = vec![
+            (vec![], DecodeError::TooShort),
+            (bytes[..7].to_vec(), DecodeError::TooShort),
+            (bytes[..100].to_vec(), DecodeError::TooShort),
+            (bytes[..8 + 16 * 49].to_vec(), DecodeError::TooShort),
+            (bytes[..bytes.len() - 1].to_vec(), DecodeError::TooShort),
+            (trailing, DecodeError::TrailingBytes { count: 1 }),
+        ];
+
+        for (bytes, err) in test_cases {
+            assert_eq!(RowProof::try_from_bytes(&bytes), Err(err));
+        }
+    }
+}
@@ -1,7 +1,45 @@
 //! Verifiable secret sharing.
 
+#[cfg(feature = "cbor")]
+mod cbor;
+mod commitment;
+mod context;
+mod encoding;
+mod errors;
+pub mod feldman;
+mod id;
 mod matrix;
+mod merkle;
+mod msm;
+mod partial;
+mod pedersen;
+mod prepared;
+pub mod reconstruction;
+pub mod reshare;
+#[cfg(feature = "serde")]
+mod serialization;
+mod table;
 mod vector;
+mod verifier;
+mod view;
 
 // Re-exports.
-pub use self::{matrix::*, vector::*};
+#[cfg(feature = "cbor")]
+pub use self::cbor::MAX_CBOR_SIZE;
+pub use self::{
+    commitment::*,
+    context::*,
+    encoding::*,
+    errors::*,
+    id::*,
+    matrix::*,
+    merkle::*,
+    partial::*,
+    pedersen::*,
+    prepared::*,
+    reconstruction::{reconstruct, reconstruct_robust},
+    table::*,
+    vector::*,
+    verifier::*,
+    view::*,
+};
@@ -0,0 +1,217 @@
+//! Multi-scalar multiplication.
+
+use std::ops::AddAssign;
+
+use group::{ff::PrimeField, prime::PrimeCurve, Group};
+
+/// The number of terms below which the naive method is used.
+const NAIVE_THRESHOLD: usize = 8;
+
+/// Returns the sum of the given points multiplied by the given scalars,
+/// i.e., `\sum_i s_i * P_i`.
+///
+/// Large inputs are processed using Pippenger's bucket method, which needs
+/// considerably fewer group operations than multiplying each point
+/// separately. Small inputs fall back to the naive method.
+///
+/// This method is not constant time as the sequence of group operations
+/// depends on the scalars, so it must not be used with secret scalars.
+///
+/// # Panics
+///
+/// Panics if the number of scalars and points differ.
+pub(crate) fn msm<G: Group>(scalars: &[G::Scalar], points: &[G]) -> G {
+    assert_eq!(
+        scalars.len(),
+        points.len(),
+        "number of scalars and points should be equal"
+    );
+
+    if scalars.len() < NAIVE_THRESHOLD {
+        return msm_naive(scalars, points);
+    }
+
+    msm_pippenger(scalars, points)
+}
+
+/// Returns the sum of the given points in affine representation multiplied
+/// by the given scalars, i.e., `\sum_i s_i * P_i`.
+///
+/// This is the same as [`msm`], except that the bucket method accumulates
+/// points using mixed additions, which are cheaper than additions of two
+/// points in projective representation.
+///
+/// This method is not constant time as the sequence of group operations
+/// depends on the scalars, so it must not be used with secret scalars.
+///
+/// # Panics
+///
+/// Panics if the number of scalars and points differ.
+pub(crate) fn msm_affine<G: PrimeCurve>(scalars: &[G::Scalar], points: &[G::Affine]) -> G {
+    assert_eq!(
+        scalars.len(),
+        points.len(),
+        "number of scalars and points should be equal"
+    );
+
+    if scalars.len() < NAIVE_THRESHOLD {
+        let mut sum = G::identity();
+        for (s, p) in scalars.iter().zip(points) {
+            sum += *p * s;
+        }
+        return sum;
+    }
+
+    pippenger(scalars, points)
+}
+
+/// Returns the sum of the given points multiplied by the given scalars
+/// by multiplying each point separately.
+pub(crate) fn msm_naive<G: Group>(scalars: &[G::Scalar], points: &[G]) -> G {
+    let mut sum = G::identity();
+    for (s, p) in scalars.iter().zip(points) {
+        sum += *p * s;
+    }
+    sum
+}
+
+/// Returns the sum of the given points multiplied by the given scalars
+/// using Pippenger's bucket method.
+///
+/// The scalars are split into windows of `c` bits. For every window,
+/// points are accumulated into `2^c - 1` buckets according to the value
+/// of their scalar's window, and the buckets are summed up so that
+/// the bucket with value `d` is counted `d` times. The window sums are
+/// then combined from the most significant window down, doubling
+/// the accumulator `c` times between windows.
+fn msm_pippenger<G: Group>(scalars: &[G::Scalar], points: &[G]) -> G {
+    pippenger(scalars, points)
+}
+
+/// Returns the sum of the given points multiplied by the given scalars
+/// using Pippenger's bucket method, where the points may be in any
+/// representation that can be added to an element of the group.
+fn pippenger<G, P>(scalars: &[G::Scalar], points: &[P]) -> G
+where
+    G: Group + for<'a> AddAssign<&'a P>,
+{
+    let bytes: Vec<_> = scalars.iter().map(to_le_bytes).collect();
+    let bits = G::Scalar::NUM_BITS as usize;
+    let c = window_size(scalars.len());
+    let windows = bits.div_ceil(c);
+
+    let mut acc = G::identity();
+    let mut buckets = vec![G::identity(); (1 << c) - 1];
+
+    for w in (0..windows).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+
+        buckets.fill(G::identity());
+        for (s, p) in bytes.iter().zip(points) {
+            let d = digit(s.as_ref(), w * c, c);
+            if d != 0 {
+                buckets[d - 1] += p;
+            }
+        }
+
+        // Compute \sum_d d * B_d as a sum of running sums.
+        let mut running = G::identity();
+        let mut sum = G::identity();
+        for b in buckets.iter().rev() {
+            running += b;
+            sum += running;
+        }
+
+        acc += sum;
+    }
+
+    acc
+}
+
+/// Returns the window size for the given number of terms.
+fn window_size(n: usize) -> usize {
+    match n {
+        0..=31 => 3,
+        _ => n.ilog2() as usize * 69 / 100 + 2, // ~ ln(n) + 2
+    }
+}
+
+/// Returns the little-endian byte representation of the given scalar.
+///
+/// The prime field trait doesn't specify the endianness of the canonical
+/// representation, so it is detected from the representation of one.
+pub(crate) fn to_le_bytes<F: PrimeField>(s: &F) -> F::Repr {
+    let mut repr = s.to_repr();
+    if F::ONE.to_repr().as_ref()[0] != 1 {
+        repr.as_mut().reverse();
+    }
+    repr
+}
+
+/// Returns the value of `width` bits of the little-endian byte representation
+/// starting at the given bit offset.
+pub(crate) fn digit(bytes: &[u8], offset: usize, width: usize) -> usize {
+    let mut d = 0;
+    for k in 0..width {
+        let bit = offset + k;
+        if let Some(byte) = bytes.get(bit / 8) {
+            d |= ((byte >> (bit % 8)) as usize & 1) << k;
+        }
+    }
+    d
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Curve, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{digit, msm, msm_affine, msm_naive, msm_pippenger, to_le_bytes};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    #[test]
+    fn test_to_le_bytes() {
+        let bytes = to_le_bytes(&PrimeField::from_u64(0x0102));
+        assert_eq!(bytes[0], 0x02);
+        assert_eq!(bytes[1], 0x01);
+        assert!(bytes[2..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_digit() {
+        let bytes = [0b1011_0110, 0b0000_0001];
+        assert_eq!(digit(&bytes, 0, 3), 0b110);
+        assert_eq!(digit(&bytes, 3, 3), 0b110);
+        assert_eq!(digit(&bytes, 6, 3), 0b110);
+        assert_eq!(digit(&bytes, 9, 3), 0);
+        assert_eq!(digit(&bytes, 15, 3), 0); // Out of bounds.
+    }
+
+    #[test]
+    fn test_msm() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for n in [0, 1, 2, 7, 8, 9, 31, 32, 100] {
+            let mut scalars: Vec<_> = (0..n).map(|_| PrimeField::random(&mut rng)).collect();
+            let points: Vec<_> = (0..n).map(|_| Group::random(&mut rng)).collect();
+
+            // Edge-case scalars.
+            let edge = [PrimeField::ZERO, PrimeField::ONE, PrimeField::ONE.neg()];
+            for (s, e) in scalars.iter_mut().zip(edge) {
+                *s = e;
+            }
+
+            let expected = msm_naive(&scalars, &points);
+            assert_eq!(msm(&scalars, &points), expected);
+            assert_eq!(msm_pippenger(&scalars, &points), expected);
+
+            let mut affine = vec![Default::default(); n];
+            Group::batch_normalize(&points, &mut affine);
+            assert_eq!(msm_affine::<Group>(&scalars, &affine), expected);
+        }
+    }
+}
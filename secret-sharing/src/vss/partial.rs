@@ -0,0 +1,234 @@
+use group::{Group, GroupEncoding};
+use sha3::digest::{Digest, Output};
+
+use super::{Error, VerificationMatrix};
+
+/// Partially decoded verification matrix.
+///
+/// The matrix keeps track of the elements that failed to decode, so that
+/// only those need to be supplied again instead of the whole matrix.
+/// Missing elements are set to the identity element of the group until
+/// they are repaired.
+#[derive(Debug, Clone)]
+pub struct PartialMatrix<G: Group> {
+    /// The verification matrix with missing elements set to the identity.
+    vm: VerificationMatrix<G>,
+    /// The bitmap of missing elements in row-major order, where
+    /// `missing[i * cols + j]` is true iff the element `m_{i,j}` is missing.
+    missing: Vec<bool>,
+}
+
+impl<G> PartialMatrix<G>
+where
+    G: Group,
+{
+    /// Creates a new partial matrix from the given verification matrix
+    /// and the bitmap of missing elements.
+    pub(crate) fn new(vm: VerificationMatrix<G>, missing: Vec<bool>) -> Self {
+        debug_assert_eq!(missing.len(), vm.rows * vm.cols);
+        Self { vm, missing }
+    }
+
+    /// Returns the dimensions (number of rows and columns) of the matrix.
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.vm.dimensions()
+    }
+
+    /// Returns the element `m_{i,j}` of the matrix, if it is not missing.
+    pub fn element(&self, i: usize, j: usize) -> Option<&G> {
+        match self.is_missing(i, j) {
+            true => None,
+            false => self.vm.element(i, j),
+        }
+    }
+
+    /// Returns true iff the element `m_{i,j}` is missing.
+    ///
+    /// Elements out of bounds are never missing.
+    pub fn is_missing(&self, i: usize, j: usize) -> bool {
+        self.index(i, j).map(|k| self.missing[k]).unwrap_or(false)
+    }
+
+    /// Returns the positions of the missing elements in row-major order.
+    pub fn missing(&self) -> Vec<(usize, usize)> {
+        let cols = self.vm.cols;
+        self.missing
+            .iter()
+            .enumerate()
+            .filter(|(_, &missing)| missing)
+            .map(|(k, _)| (k / cols, k % cols))
+            .collect()
+    }
+
+    /// Returns true iff no elements are missing.
+    pub fn is_complete(&self) -> bool {
+        !self.missing.iter().any(|&missing| missing)
+    }
+
+    /// Sets the given missing elements `(i, j, m_{i,j})`.
+    ///
+    /// The entries are validated before any of them is applied, so that
+    /// the matrix is left unchanged on error.
+    pub fn repair(&mut self, entries: &[(usize, usize, G)]) -> Result<(), Error> {
+        for &(i, j, _) in entries {
+            let k = self.index(i, j).ok_or(Error::ElementOutOfBounds)?;
+            if !self.missing[k] {
+                return Err(Error::ElementAlreadyPresent);
+            }
+        }
+
+        for &(i, j, mij) in entries {
+            let k = i * self.vm.cols + j;
+            self.vm.m[k] = mij;
+            self.missing[k] = false;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the verification matrix if no elements are missing.
+    pub fn finalize(self) -> Result<VerificationMatrix<G>, Error> {
+        if !self.is_complete() {
+            return Err(Error::MissingElements);
+        }
+
+        Ok(self.vm)
+    }
+
+    /// Returns the index of the element `m_{i,j}` in the bitmap.
+    fn index(&self, i: usize, j: usize) -> Option<usize> {
+        if i >= self.vm.rows || j >= self.vm.cols {
+            return None;
+        }
+        Some(i * self.vm.cols + j)
+    }
+}
+
+impl<G> PartialMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Returns the verification matrix if no elements are missing and
+    /// its digest, as computed by [`VerificationMatrix::digest`], matches
+    /// the expected one.
+    ///
+    /// The digest check catches repairs that supplied wrong elements.
+    pub fn finalize_with_digest<D: Digest>(
+        self,
+        expected: &Output<D>,
+    ) -> Result<VerificationMatrix<G>, Error> {
+        let vm = self.finalize()?;
+        if vm.digest::<D>() != *expected {
+            return Err(Error::DigestMismatch);
+        }
+
+        Ok(vm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+    use sha3::Sha3_256;
+
+    use crate::{
+        poly,
+        vss::{self, Error},
+    };
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    #[test]
+    fn test_repair() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let size = VerificationMatrix::element_byte_size();
+
+        let corrupted = vec![(0, 0), (1, 2), (2, 3)];
+        let mut bytes = vm.to_bytes();
+        for &(i, j) in &corrupted {
+            bytes[2 + (i * 4 + j) * size] = 0xFF;
+        }
+
+        let (mut pm, missing) =
+            VerificationMatrix::from_bytes_lossy(&bytes).expect("deserialization should succeed");
+        assert_eq!(missing, corrupted);
+        assert!(!pm.is_complete());
+        assert!(pm.is_missing(1, 2));
+        assert!(pm.element(1, 2).is_none());
+        assert_eq!(pm.element(1, 1), vm.element(1, 1));
+
+        // Finalization fails while elements are missing.
+        assert!(pm.clone().finalize().is_err());
+
+        // Out-of-bounds and already present elements are rejected
+        // and leave the matrix unchanged.
+        let m00 = *vm.element(0, 0).unwrap();
+        let m11 = *vm.element(1, 1).unwrap();
+        assert!(pm.repair(&[(0, 0, m00), (3, 0, m00)]).is_err());
+        assert!(pm.repair(&[(0, 0, m00), (1, 1, m11)]).is_err());
+        assert_eq!(pm.missing(), corrupted);
+
+        // Repair missing elements one batch at a time.
+        let entries: Vec<_> = corrupted
+            .iter()
+            .map(|&(i, j)| (i, j, *vm.element(i, j).unwrap()))
+            .collect();
+        pm.repair(&entries[..1]).expect("repair should succeed");
+        assert_eq!(pm.missing(), &corrupted[1..]);
+        pm.repair(&entries[1..]).expect("repair should succeed");
+        assert!(pm.is_complete());
+
+        // Elements cannot be repaired twice.
+        assert!(pm.repair(&entries[..1]).is_err());
+
+        let restored = pm.finalize().expect("finalization should succeed");
+        assert_eq!(restored, vm);
+    }
+
+    #[test]
+    fn test_finalize_with_digest() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let digest = vm.digest::<Sha3_256>();
+        let size = VerificationMatrix::element_byte_size();
+
+        let corrupted = vec![(0, 0), (1, 2), (2, 3)];
+        let mut bytes = vm.to_bytes();
+        for &(i, j) in &corrupted {
+            bytes[2 + (i * 4 + j) * size] = 0xFF;
+        }
+        let (pm, _) =
+            VerificationMatrix::from_bytes_lossy(&bytes).expect("deserialization should succeed");
+
+        // Finalization fails while elements are missing.
+        let res = pm.clone().finalize_with_digest::<Sha3_256>(&digest);
+        assert!(matches!(res, Err(Error::MissingElements)));
+
+        // A wrong element is caught by the digest check.
+        let mut entries: Vec<_> = corrupted
+            .iter()
+            .map(|&(i, j)| (i, j, *vm.element(i, j).unwrap()))
+            .collect();
+        let mut wrong = pm.clone();
+        entries[1].2 = *vm.element(1, 1).unwrap();
+        wrong.repair(&entries).expect("repair should succeed");
+        let res = wrong.clone().finalize_with_digest::<Sha3_256>(&digest);
+        assert!(matches!(res, Err(Error::DigestMismatch)));
+        assert!(wrong.finalize().is_ok());
+
+        // The correct elements pass the digest check.
+        let mut pm = pm;
+        entries[1].2 = *vm.element(1, 2).unwrap();
+        pm.repair(&entries).expect("repair should succeed");
+        let restored = pm
+            .finalize_with_digest::<Sha3_256>(&digest)
+            .expect("finalization should succeed");
+        assert_eq!(restored, vm);
+    }
+}
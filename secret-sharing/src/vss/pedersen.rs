@@ -0,0 +1,177 @@
+use group::Group;
+
+use crate::poly::{powers, BivariatePolynomial};
+
+use super::{msm::msm, Error, GeneratorMul, VerificationMatrix};
+
+/// Pedersen verification matrix for a bivariate polynomial.
+///
+/// Unlike the Feldman verification matrix, which reveals `b_{i,j} * G`
+/// and thereby `B(0,0) * G`, the elements of the Pedersen verification
+/// matrix are perfectly hiding commitments to the coefficients
+/// of the secret polynomial `B(x,y)`, blinded by the coefficients
+/// of a random blinding polynomial `B'(x,y)` of the same degrees:
+///
+/// ```text
+/// M_{i,j} = b_{i,j} * G + b'_{i,j} * H
+/// ```
+///
+/// The generator `H` must be independent of `G`, i.e., no one may know
+/// the discrete logarithm of `H` with respect to `G`, as otherwise
+/// the commitments are not binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PedersenMatrix<G: Group> {
+    /// The commitments to the coefficients, where `m[i][j]` represents
+    /// the element `b_{i,j} * G + b'_{i,j} * H`.
+    m: VerificationMatrix<G>,
+    /// The blinding generator `H`.
+    h: G,
+}
+
+impl<G> PedersenMatrix<G>
+where
+    G: Group,
+{
+    /// Creates a Pedersen verification matrix from the given secret
+    /// and blinding polynomials, using the given blinding generator.
+    ///
+    /// The blinding polynomial must be freshly sampled at random for every
+    /// secret polynomial.
+    ///
+    /// Returns an error if the polynomials are not of the same degrees,
+    /// or if their degrees exceed the limits of the serialization format.
+    pub fn new(
+        bp: &BivariatePolynomial<G::Scalar>,
+        blinding: &BivariatePolynomial<G::Scalar>,
+        h: G,
+    ) -> Result<Self, Error> {
+        if bp.deg_x != blinding.deg_x || bp.deg_y != blinding.deg_y {
+            return Err(Error::InvalidDegrees);
+        }
+
+        let mut m = Vec::with_capacity(bp.deg_x + 1);
+        for (bi, ri) in bp.b.iter().zip(blinding.b.iter()) {
+            let mut mi = Vec::with_capacity(bp.deg_y + 1);
+            for (bij, rij) in bi.iter().zip(ri.iter()) {
+                mi.push(G::mul_generator(bij) + h * rij) // b_{i,j} * G + b'_{i,j} * H
+            }
+            m.push(mi);
+        }
+        let m = VerificationMatrix::new(m)?;
+
+        Ok(Self { m, h })
+    }
+
+    /// Returns the dimensions (number of rows and columns) of the verification
+    /// matrix.
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.m.dimensions()
+    }
+
+    /// Returns the element `M_{i,j}`, if it exists.
+    pub fn element(&self, i: usize, j: usize) -> Option<&G> {
+        self.m.element(i, j)
+    }
+
+    /// Returns the blinding generator `H`.
+    pub fn h(&self) -> &G {
+        &self.h
+    }
+
+    /// Verifies whether the secret and blinding polynomials evaluate
+    /// to the given values, i.e., if it holds
+    /// `B(x,y) * G + B'(x,y) * H == v * G + r * H`.
+    pub fn verify(&self, x: &G::Scalar, y: &G::Scalar, v: &G::Scalar, r: &G::Scalar) -> bool {
+        let xpows = powers(x, self.m.deg_x()); // [x^i]
+        let ypows = powers(y, self.m.deg_y()); // [y^j]
+        let mut scalars = Vec::with_capacity(xpows.len() * ypows.len());
+        for xpow in xpows {
+            scalars.extend(ypows.iter().map(|ypow| xpow * ypow)); // x^i * y^j
+        }
+        let points: Vec<_> = self.m.iter().map(|(_, _, mij)| *mij).collect();
+
+        // The values may be secret, so keep them out of the multi-scalar
+        // multiplication, which is not constant time.
+        let mut diff = msm(&scalars, &points); // \sum_{i,j} x^i * y^j * M_{i,j}
+        diff -= G::mul_generator(v);
+        diff -= self.h * r;
+
+        diff.is_identity().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{poly, vss::Error};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type PedersenMatrix = super::PedersenMatrix<Group>;
+    type VerificationMatrix = crate::vss::VerificationMatrix<Group>;
+
+    #[test]
+    fn test_new() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let h = Group::random(&mut rng);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let blinding = BivariatePolynomial::random(2, 3, &mut rng);
+
+        let pm = PedersenMatrix::new(&bp, &blinding, h).expect("matrix should be created");
+        assert_eq!(pm.dimensions(), (3, 4));
+        assert_eq!(pm.h(), &h);
+        for i in 0..3 {
+            for j in 0..4 {
+                let mij = Group::generator() * bp.b[i][j] + h * blinding.b[i][j];
+                assert_eq!(pm.element(i, j), Some(&mij));
+            }
+        }
+        assert_eq!(pm.element(3, 0), None);
+
+        // The commitments hide the secret polynomial.
+        let vm = VerificationMatrix::from(&bp);
+        assert_ne!(pm.element(0, 0), vm.element(0, 0));
+
+        // Degrees must match.
+        let blinding = BivariatePolynomial::random(3, 2, &mut rng);
+        let res = PedersenMatrix::new(&bp, &blinding, h);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            Error::InvalidDegrees.to_string()
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let h = Group::random(&mut rng);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let blinding = BivariatePolynomial::random(2, 3, &mut rng);
+        let pm = PedersenMatrix::new(&bp, &blinding, h).expect("matrix should be created");
+
+        for _ in 0..5 {
+            let x = PrimeField::random(&mut rng);
+            let y = PrimeField::random(&mut rng);
+            let v = bp.eval(&x, &y);
+            let r = blinding.eval(&x, &y);
+
+            // Valid values.
+            assert!(pm.verify(&x, &y, &v, &r));
+
+            // Invalid secret value.
+            assert!(!pm.verify(&x, &y, &(v + PrimeField::ONE), &r));
+
+            // Invalid blinding value.
+            assert!(!pm.verify(&x, &y, &v, &(r + PrimeField::ONE)));
+
+            // Swapped values.
+            assert!(!pm.verify(&x, &y, &r, &v));
+
+            // Invalid point.
+            assert!(!pm.verify(&y, &x, &v, &r));
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use group::prime::{PrimeCurve, PrimeCurveAffine};
+use subtle::Choice;
+
+use crate::poly::{powers, Polynomial};
+
+use super::{msm::msm_affine, GeneratorMul, VerificationMatrix};
+
+/// Verification matrix whose elements are converted to affine representation
+/// for repeated verification.
+///
+/// Multi-scalar multiplications over affine points use mixed additions,
+/// which on curves with projective representations are cheaper than
+/// additions of two projective points. The elements are converted using
+/// the curve's batch normalization, which needs a single field inversion
+/// for the whole matrix, so preparation pays off when the same matrix
+/// is verified against more than a few evaluations.
+#[derive(Debug, Clone)]
+pub struct PreparedVerificationMatrix<G: PrimeCurve> {
+    /// The number of rows in the verification matrix.
+    rows: usize,
+    /// The number of columns in the verification matrix.
+    cols: usize,
+    /// The verification matrix elements in affine representation
+    /// in row-major order, where `m[i * cols + j]` represents the element
+    /// `b_{i,j} * G`.
+    m: Vec<G::Affine>,
+}
+
+impl<G> PreparedVerificationMatrix<G>
+where
+    G: PrimeCurve,
+{
+    /// Prepares the given verification matrix for repeated verification.
+    pub fn new(vm: &VerificationMatrix<G>) -> Self {
+        let mut m = vec![G::Affine::identity(); vm.m.len()];
+        G::batch_normalize(&vm.m, &mut m);
+
+        Self {
+            rows: vm.rows,
+            cols: vm.cols,
+            m,
+        }
+    }
+
+    /// Returns the dimensions (number of rows and columns) of the verification
+    /// matrix.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Verifies whether the underlying bivariate polynomial evaluates
+    /// to the given value, i.e., if it holds `B(x,y) == v`.
+    ///
+    /// See [`VerificationMatrix::verify`] for details.
+    pub fn verify(&self, x: &G::Scalar, y: &G::Scalar, v: &G::Scalar) -> bool {
+        let xpows = powers(x, self.rows - 1); // [x^i]
+        let ypows = powers(y, self.cols - 1); // [y^j]
+        let mut scalars = Vec::with_capacity(self.rows * self.cols);
+        for xpow in xpows {
+            scalars.extend(ypows.iter().map(|ypow| xpow * ypow)); // x^i * y^j
+        }
+
+        // The value may be secret, so keep it out of the multi-scalar
+        // multiplication, which is not constant time.
+        let mut diff = msm_affine::<G>(&scalars, &self.m); // \sum_{i,j} x^i * y^j * M_{i,j}
+        diff -= G::mul_generator(v);
+
+        diff.is_identity().into()
+    }
+
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate x against
+    /// the verification matrix.
+    ///
+    /// See [`VerificationMatrix::verify_x`] for details.
+    pub fn verify_x(&self, x: &G::Scalar, polynomial: &Polynomial<G::Scalar>) -> bool {
+        // Short-circuit on the size of the polynomial, not its contents.
+        if polynomial.size() != self.cols {
+            return false;
+        }
+
+        // Don't short-circuit this loop to avoid revealing which coefficient
+        // failed to verify.
+        let xpows = powers(x, self.rows - 1); // [x^i]
+        let mut verified = Choice::from(1);
+
+        for j in 0..self.cols {
+            let aj = polynomial.coefficient(j).expect("size checked above");
+            let mj: Vec<_> = self.m[j..].iter().step_by(self.cols).copied().collect(); // [M_{i,j}]
+            let mut diff = G::mul_generator(aj); // a_j * G
+            diff -= msm_affine::<G>(&xpows, &mj); // \sum_i x^i * M_{i,j}
+
+            verified &= diff.is_identity();
+        }
+
+        verified.into()
+    }
+
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate y against
+    /// the verification matrix.
+    ///
+    /// See [`VerificationMatrix::verify_y`] for details.
+    pub fn verify_y(&self, y: &G::Scalar, polynomial: &Polynomial<G::Scalar>) -> bool {
+        // Short-circuit on the size of the polynomial, not its contents.
+        if polynomial.size() != self.rows {
+            return false;
+        }
+
+        // Don't short-circuit this loop to avoid revealing which coefficient
+        // failed to verify.
+        let ypows = powers(y, self.cols - 1); // [y^j]
+        let mut verified = Choice::from(1);
+
+        for (i, mi) in self.m.chunks(self.cols).enumerate() {
+            let ai = polynomial.coefficient(i).expect("size checked above");
+            let mut diff = G::mul_generator(ai); // a_i * G
+            diff -= msm_affine::<G>(&ypows, mi); // \sum_j y^j * M_{i,j}
+
+            verified &= diff.is_identity();
+        }
+
+        verified.into()
+    }
+}
+
+impl<G> From<&VerificationMatrix<G>> for PreparedVerificationMatrix<G>
+where
+    G: PrimeCurve,
+{
+    fn from(vm: &VerificationMatrix<G>) -> Self {
+        Self::new(vm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{poly, vss};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+    type PreparedVerificationMatrix = vss::PreparedVerificationMatrix<Group>;
+
+    #[test]
+    fn test_verify() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (deg_x, deg_y) in [(0, 0), (1, 2), (4, 2), (5, 10)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let vm = VerificationMatrix::from(&bp);
+            let prepared = vm.prepare();
+            assert_eq!(prepared.dimensions(), vm.dimensions());
+
+            for _ in 0..3 {
+                let x = PrimeField::random(&mut rng);
+                let y = PrimeField::random(&mut rng);
+
+                let v = bp.eval(&x, &y);
+                assert!(prepared.verify(&x, &y, &v));
+                assert!(!prepared.verify(&x, &y, &(v + PrimeField::ONE)));
+
+                let p = bp.eval_x(&x);
+                assert!(prepared.verify_x(&x, &p));
+                assert_eq!(prepared.verify_x(&y, &p), deg_x == 0);
+
+                let p = bp.eval_y(&y);
+                assert!(prepared.verify_y(&y, &p));
+                assert_eq!(prepared.verify_y(&x, &p), deg_y == 0);
+            }
+        }
+
+        // Polynomials of invalid size.
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let prepared = PreparedVerificationMatrix::from(&VerificationMatrix::from(&bp));
+        let x = PrimeField::random(&mut rng);
+        assert!(!prepared.verify_x(&x, &bp.eval_y(&x)));
+        assert!(!prepared.verify_y(&x, &bp.eval_x(&x)));
+    }
+
+    fn bench_verify(b: &mut Bencher, prepared: bool) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(29, 59, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let pvm = vm.prepare();
+        let x = PrimeField::from_u64(2);
+        let y = PrimeField::from_u64(3);
+        let v = bp.eval(&x, &y);
+
+        b.iter(|| match prepared {
+            true => pvm.verify(&x, &y, &v),
+            false => vm.verify(&x, &y, &v),
+        });
+    }
+
+    #[bench]
+    fn bench_verify_prepared_30_60(b: &mut Bencher) {
+        bench_verify(b, true)
+    }
+
+    #[bench]
+    fn bench_verify_unprepared_30_60(b: &mut Bencher) {
+        bench_verify(b, false)
+    }
+}
@@ -0,0 +1,463 @@
+//! Secret reconstruction and reconstruction diagnostics.
+
+use group::{
+    ff::{Field, PrimeField},
+    Group,
+};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::poly::{lagrange::lagrange_at_zero_ct, powers, Point, Polynomial};
+
+use super::{Error, VerificationVector};
+
+/// An assessment of whether a secret can be reconstructed from the given
+/// shares.
+#[derive(Debug)]
+pub struct Assessment<F> {
+    /// The x-coordinates of the expected shareholders whose shares are valid.
+    pub valid: Vec<F>,
+    /// The x-coordinates of the expected shareholders whose shares are
+    /// invalid, together with the reason.
+    pub invalid: Vec<(F, Error)>,
+    /// The x-coordinates of the expected shareholders that haven't
+    /// provided a share.
+    pub missing: Vec<F>,
+    /// The x-coordinates of the shares from shareholders that are not
+    /// expected.
+    pub foreign: Vec<F>,
+    /// Indicates whether there are enough valid shares to reconstruct
+    /// the secret.
+    pub can_reconstruct: bool,
+}
+
+/// Assesses the given shares against the verification vector of the secret
+/// polynomial and the set of expected shareholders.
+///
+/// Shares from shareholders that are not expected are neither verified nor
+/// counted, but are reported separately. Only the first share from each
+/// expected shareholder is considered, subsequent ones are reported
+/// as duplicates.
+///
+/// The secret can be reconstructed if at least `threshold + 1` shares
+/// are valid. No interpolation is performed.
+pub fn assess<G>(
+    shares: &[Point<G::Scalar>],
+    vv: &VerificationVector<G>,
+    expected: &[G::Scalar],
+    threshold: u8,
+) -> Assessment<G::Scalar>
+where
+    G: Group,
+{
+    let mut valid = Vec::new();
+    let mut invalid = Vec::new();
+    let mut foreign = Vec::new();
+    let mut seen = Vec::new();
+
+    for share in shares {
+        if !expected.contains(&share.x) {
+            foreign.push(share.x);
+            continue;
+        }
+        if seen.contains(&share.x) {
+            invalid.push((share.x, Error::DuplicateShare));
+            continue;
+        }
+        seen.push(share.x);
+
+        match vv.verify(&share.x, &share.y) {
+            true => valid.push(share.x),
+            false => invalid.push((share.x, Error::InvalidShare)),
+        }
+    }
+
+    let missing = expected
+        .iter()
+        .filter(|x| !seen.contains(x))
+        .copied()
+        .collect();
+
+    let can_reconstruct = valid.len() > threshold as usize;
+
+    Assessment {
+        valid,
+        invalid,
+        missing,
+        foreign,
+        can_reconstruct,
+    }
+}
+
+/// Reconstructs the secret from the given shares `(x_i, y_i)` by evaluating
+/// their Lagrange interpolation polynomial at zero, or returns `None`
+/// if fewer than `threshold + 1` shares are given or the x-coordinates
+/// are not unique.
+///
+/// The shares are not verified, so the caller should verify them beforehand
+/// if a verification matrix or vector is available.
+pub fn reconstruct<G>(shares: &[(G::Scalar, G::Scalar)], threshold: usize) -> Option<G::Scalar>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    if shares.len() <= threshold {
+        return None;
+    }
+
+    let xs: Vec<_> = shares.iter().map(|(x, _)| *x).collect();
+    let cs = lagrange_at_zero_ct(&xs)?;
+
+    let mut secret = G::Scalar::ZERO;
+    let mut term = G::Scalar::ZERO;
+    for ((_, y), c) in shares.iter().zip(cs.iter()) {
+        term = *y * c; // L_i(0) * y_i
+        secret += term;
+    }
+    term.zeroize();
+
+    Some(secret)
+}
+
+/// Reconstructs the polynomial of the given degree from the given shares
+/// `(x_i, y_i)`, of which at most `max_errors` may be corrupted, using
+/// the Berlekamp-Welch algorithm.
+///
+/// Decoding succeeds if at least `degree + 2 * max_errors + 1` shares
+/// are given and at most `max_errors` of them don't lie on the polynomial.
+/// Returns `None` if there are too few shares, the x-coordinates are not
+/// unique, or more shares are corrupted than can be corrected.
+///
+/// The polynomial `P(x)` is found by solving the linear system
+/// ```text
+/// Q(x_i) = y_i * E(x_i)
+/// ```
+/// for a monic error-locator polynomial `E(x)` of degree `max_errors`,
+/// whose roots include the x-coordinates of the corrupted shares,
+/// and a polynomial `Q(x) = P(x) * E(x)`, and dividing `Q(x)` by `E(x)`.
+///
+/// This method is not constant time.
+pub fn reconstruct_robust<F>(
+    shares: &[(F, F)],
+    degree: usize,
+    max_errors: usize,
+) -> Option<Polynomial<F>>
+where
+    F: PrimeField + Zeroize,
+{
+    let n = shares.len();
+    if n < degree + 2 * max_errors + 1 {
+        return None;
+    }
+    for (i, (xi, _)) in shares.iter().enumerate() {
+        if shares[i + 1..].iter().any(|(xj, _)| xi == xj) {
+            return None;
+        }
+    }
+
+    let mut p = match max_errors {
+        0 => Polynomial::interpolate(&shares[..=degree])?,
+        _ => berlekamp_welch(shares, degree, max_errors)?,
+    };
+
+    // Valid codewords have at most the given number of errors, so reject
+    // polynomials that disagree with more shares.
+    let errors = shares.iter().filter(|(x, y)| p.eval(x) != *y).count();
+    if errors > max_errors || p.size() > degree + 1 {
+        p.zeroize();
+        return None;
+    }
+    p.a.resize(degree + 1, F::ZERO);
+
+    Some(p)
+}
+
+/// Returns the quotient `Q(x) / E(x)` for a solution of the Berlekamp-Welch
+/// linear system, or `None` if the system has no solution or the division
+/// leaves a remainder.
+fn berlekamp_welch<F>(shares: &[(F, F)], degree: usize, max_errors: usize) -> Option<Polynomial<F>>
+where
+    F: PrimeField + Zeroize,
+{
+    // The unknowns are the coefficients e_0, ..., e_{e-1} of the monic
+    // polynomial E(x) followed by the coefficients q_0, ..., q_{e+k}
+    // of Q(x), and each share contributes the equation:
+    //   \sum_j q_j x_i^j - y_i \sum_l e_l x_i^l = y_i x_i^e
+    let unknowns = 2 * max_errors + degree + 1;
+    let mut system = Zeroizing::new(Vec::with_capacity(shares.len()));
+    for (x, y) in shares {
+        let xpows = powers(x, max_errors + degree); // [x^j]
+        let mut row = Vec::with_capacity(unknowns + 1);
+        row.extend(xpows[..max_errors].iter().map(|xpow| -*y * xpow));
+        row.extend(xpows.iter().copied());
+        row.push(*y * xpows[max_errors]);
+        system.push(row);
+    }
+
+    let solution = Zeroizing::new(solve(&mut system, unknowns)?);
+    let mut e = solution[..max_errors].to_vec();
+    e.push(F::ONE);
+    let e = Polynomial::with_coefficients(e);
+    let q = Polynomial::with_coefficients(solution[max_errors..].to_vec());
+
+    let (p, r) = q.div_rem(&e)?;
+    if r.size() != 1 || !bool::from(r.a[0].is_zero()) {
+        return None;
+    }
+
+    Some(p)
+}
+
+/// Solves the given linear system, whose rows are the coefficients
+/// of the unknowns followed by the constant term, using Gauss-Jordan
+/// elimination.
+///
+/// Returns one of the solutions, with free unknowns set to zero,
+/// or `None` if the system is inconsistent.
+fn solve<F: PrimeField>(system: &mut [Vec<F>], unknowns: usize) -> Option<Vec<F>> {
+    let mut pivots = Vec::with_capacity(unknowns);
+    let mut rank = 0;
+
+    for col in 0..unknowns {
+        let Some(pivot) = (rank..system.len()).find(|&i| !bool::from(system[i][col].is_zero()))
+        else {
+            continue;
+        };
+        system.swap(rank, pivot);
+
+        let inv = system[rank][col]
+            .invert()
+            .expect("pivot should be non-zero");
+        for v in system[rank].iter_mut() {
+            *v *= inv;
+        }
+
+        for i in 0..system.len() {
+            if i == rank {
+                continue;
+            }
+            let c = system[i][col];
+            if bool::from(c.is_zero()) {
+                continue;
+            }
+            let (lhs, rhs) = match i < rank {
+                true => {
+                    let (lhs, rhs) = system.split_at_mut(rank);
+                    (&mut lhs[i], &rhs[0])
+                }
+                false => {
+                    let (lhs, rhs) = system.split_at_mut(i);
+                    (&mut rhs[0], &lhs[rank])
+                }
+            };
+            for (a, b) in lhs.iter_mut().zip(rhs.iter()) {
+                *a -= c * b;
+            }
+        }
+
+        pivots.push(col);
+        rank += 1;
+    }
+
+    // The remaining rows have zero coefficients, so the system is consistent
+    // only if their constant terms are zero as well.
+    if system[rank..]
+        .iter()
+        .any(|row| !bool::from(row[unknowns].is_zero()))
+    {
+        return None;
+    }
+
+    let mut solution = vec![F::ZERO; unknowns];
+    for (row, col) in pivots.into_iter().enumerate() {
+        solution[col] = system[row][unknowns];
+    }
+
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        poly::{self, Point},
+        vss::{self, Error},
+    };
+
+    use super::{assess, reconstruct, reconstruct_robust};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type Polynomial = poly::Polynomial<PrimeField>;
+    type VerificationVector = vss::VerificationVector<Group>;
+
+    fn scalar(value: i64) -> PrimeField {
+        scalars(&[value])[0]
+    }
+
+    fn scalars(values: &[i64]) -> Vec<PrimeField> {
+        values
+            .iter()
+            .map(|&w| match w.is_negative() {
+                false => PrimeField::from_u64(w as u64),
+                true => PrimeField::from_u64(-w as u64).neg(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_assess() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let p = Polynomial::random(threshold, &mut rng);
+        let vv = VerificationVector::from(&p);
+        let expected = scalars(&[1, 2, 3, 4, 5]);
+        let share = |x: i64| Point::new(scalar(x), p.eval(&scalar(x)));
+        let fake = |x: i64| Point::new(scalar(x), scalar(x));
+
+        // Above threshold.
+        let shares: Vec<_> = (1..=5).map(share).collect();
+        let a = assess(&shares, &vv, &expected, threshold);
+        assert_eq!(a.valid, expected);
+        assert!(a.invalid.is_empty());
+        assert!(a.missing.is_empty());
+        assert!(a.foreign.is_empty());
+        assert!(a.can_reconstruct);
+
+        // Exactly at threshold.
+        let shares = vec![share(5), share(1), fake(2), share(3)];
+        let a = assess(&shares, &vv, &expected, threshold);
+        assert_eq!(a.valid, scalars(&[5, 1, 3]));
+        assert_eq!(a.invalid.len(), 1);
+        assert_eq!(a.invalid[0].0, scalar(2));
+        assert_eq!(a.invalid[0].1.to_string(), Error::InvalidShare.to_string());
+        assert_eq!(a.missing, scalars(&[4]));
+        assert!(a.can_reconstruct);
+
+        // Below threshold.
+        let shares = vec![share(1), fake(2), share(1), share(4)];
+        let a = assess(&shares, &vv, &expected, threshold);
+        assert_eq!(a.valid, scalars(&[1, 4]));
+        assert_eq!(a.invalid.len(), 2);
+        assert_eq!(a.invalid[0].0, scalar(2));
+        assert_eq!(a.invalid[0].1.to_string(), Error::InvalidShare.to_string());
+        assert_eq!(a.invalid[1].0, scalar(1));
+        assert_eq!(
+            a.invalid[1].1.to_string(),
+            Error::DuplicateShare.to_string()
+        );
+        assert_eq!(a.missing, scalars(&[3, 5]));
+        assert!(!a.can_reconstruct);
+
+        // Foreign shareholders.
+        let shares = vec![share(1), share(6), share(2), fake(7)];
+        let a = assess(&shares, &vv, &expected, threshold);
+        assert_eq!(a.valid, scalars(&[1, 2]));
+        assert!(a.invalid.is_empty());
+        assert_eq!(a.missing, scalars(&[3, 4, 5]));
+        assert_eq!(a.foreign, scalars(&[6, 7]));
+        assert!(!a.can_reconstruct);
+
+        // No shares.
+        let a = assess(&[], &vv, &expected, threshold);
+        assert!(a.valid.is_empty());
+        assert_eq!(a.missing, expected);
+        assert!(!a.can_reconstruct);
+    }
+
+    #[test]
+    fn test_reconstruct() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let p = Polynomial::random(threshold, &mut rng);
+        let secret = p.eval(&PrimeField::ZERO);
+        let share = |x: i64| (scalar(x), p.eval(&scalar(x)));
+
+        // Exactly at threshold.
+        let shares: Vec<_> = [3, 1, 5].iter().copied().map(share).collect();
+        let res = reconstruct::<Group>(&shares, threshold.into());
+        assert_eq!(res, Some(secret));
+
+        // Above threshold.
+        let shares: Vec<_> = (1..=5).map(share).collect();
+        let res = reconstruct::<Group>(&shares, threshold.into());
+        assert_eq!(res, Some(secret));
+
+        // Below threshold.
+        let shares: Vec<_> = [1, 2].iter().copied().map(share).collect();
+        let res = reconstruct::<Group>(&shares, threshold.into());
+        assert_eq!(res, None);
+
+        // Repeated x-coordinates.
+        let shares: Vec<_> = [1, 2, 1].iter().copied().map(share).collect();
+        let res = reconstruct::<Group>(&shares, threshold.into());
+        assert_eq!(res, None);
+
+        let shares = vec![share(1), share(2), share(3), (scalar(2), scalar(7))];
+        let res = reconstruct::<Group>(&shares, threshold.into());
+        assert_eq!(res, None);
+
+        // Invalid share.
+        let shares = vec![share(1), share(2), (scalar(3), scalar(7))];
+        let res = reconstruct::<Group>(&shares, threshold.into());
+        assert_ne!(res, Some(secret));
+
+        // No shares.
+        let res = reconstruct::<Group>(&[], 0);
+        assert_eq!(res, None);
+    }
+
+    #[test]
+    fn test_reconstruct_robust() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let degree = 2;
+        let p = Polynomial::random(degree, &mut rng);
+        let share = |x: i64| (scalar(x), p.eval(&scalar(x)));
+        let corrupt = |(x, y): (PrimeField, PrimeField)| (x, y + scalar(1));
+
+        for max_errors in 0..=3 {
+            let n = degree as i64 + 2 * max_errors as i64 + 1;
+            let mut shares: Vec<_> = (1..=n).map(share).collect();
+
+            // Up to the maximum number of corrupted shares.
+            for errors in 0..=max_errors {
+                let res = reconstruct_robust(&shares, degree.into(), max_errors).unwrap();
+                assert_eq!(res.a, p.a, "{errors} of {max_errors} errors");
+
+                if errors < max_errors {
+                    shares[2 * errors] = corrupt(shares[2 * errors]);
+                }
+            }
+
+            // Too many corrupted shares.
+            let k = shares.len() - 1;
+            shares[k] = corrupt(shares[k]);
+            let res = reconstruct_robust(&shares, degree.into(), max_errors);
+            assert!(res.is_none_or(|res| res.a != p.a));
+
+            // Too few shares.
+            let res = reconstruct_robust(&shares[1..], degree.into(), max_errors);
+            assert!(res.is_none());
+        }
+
+        // More shares than necessary.
+        let mut shares: Vec<_> = (1..=10).map(share).collect();
+        shares[3] = corrupt(shares[3]);
+        shares[7] = (scalar(8), scalar(0));
+        let res = reconstruct_robust(&shares, degree.into(), 2).unwrap();
+        assert_eq!(res.a, p.a);
+
+        // Polynomial of lower degree is padded.
+        let q = Polynomial::with_coefficients(scalars(&[1, 2]));
+        let shares: Vec<_> = (1..=7).map(|x| (scalar(x), q.eval(&scalar(x)))).collect();
+        let res = reconstruct_robust(&shares, 3, 1).unwrap();
+        assert_eq!(res.a, scalars(&[1, 2, 0, 0]));
+
+        // Repeated x-coordinates.
+        let shares: Vec<_> = [1, 2, 3, 4, 1].iter().copied().map(share).collect();
+        assert!(reconstruct_robust(&shares, degree.into(), 1).is_none());
+
+        // No shares.
+        assert!(reconstruct_robust::<PrimeField>(&[], 0, 0).is_none());
+    }
+}
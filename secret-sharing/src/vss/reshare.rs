@@ -0,0 +1,105 @@
+//! Proactive refreshing of shared secrets.
+//!
+//! Shares are refreshed by adding a random zero-hole bivariate polynomial
+//! `D(x,y)`, i.e., one with `D(0,0) = 0`, to the shared polynomial `B(x,y)`:
+//! ```text
+//!     B'(x,y) = B(x,y) + D(x,y)
+//! ```
+//! The secret `B'(0,0) = B(0,0)` is unchanged, while shares of `B'(x,y)`
+//! can't be combined with shares of `B(x,y)`, so shares leaked before
+//! the refresh become useless.
+
+use std::convert::TryFrom;
+
+use group::Group;
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use crate::poly::{ops::add_assign_slices, BivariatePolynomial, SecretBivariatePolynomial};
+
+use super::VerificationMatrix;
+
+/// Refreshes the given bivariate polynomial by adding a random zero-hole
+/// polynomial of the same degrees, and returns the refreshed polynomial
+/// together with the verification matrix of the added polynomial.
+///
+/// Peers can check that the verification matrix of the delta is zero-hole,
+/// which proves that the secret was preserved. As verification matrices
+/// are additive, adding it to the verification matrix of the current
+/// polynomial yields the verification matrix of the refreshed one:
+/// ```text
+///     M' = M + M_D
+/// ```
+///
+/// # Panics
+///
+/// Panics if the degrees of the polynomial exceed 255.
+pub fn refresh<G>(
+    current: &BivariatePolynomial<G::Scalar>,
+    rng: &mut impl RngCore,
+) -> (BivariatePolynomial<G::Scalar>, VerificationMatrix<G>)
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    let deg_x = u8::try_from(current.deg_x).expect("degree should fit into a byte");
+    let deg_y = u8::try_from(current.deg_y).expect("degree should fit into a byte");
+
+    let delta = BivariatePolynomial::random_with_zero_hole(deg_x, deg_y, rng);
+    let delta = SecretBivariatePolynomial::new(delta);
+
+    let mut refreshed = current.clone();
+    for (bi, di) in refreshed.b.iter_mut().zip(delta.b.iter()) {
+        add_assign_slices(bi, di); // b_{i,j} + d_{i,j}
+    }
+    let vm = VerificationMatrix::from(&*delta);
+
+    (refreshed, vm)
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{poly, vss};
+
+    use super::refresh;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    #[test]
+    fn test_refresh() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = PrimeField::random(&mut rng);
+        let bp = BivariatePolynomial::random_with_secret(2, 3, secret, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let (refreshed, delta_vm) = refresh::<Group>(&bp, &mut rng);
+
+        // The secret is preserved, which the delta proves.
+        assert_eq!(refreshed.deg_x, 2);
+        assert_eq!(refreshed.deg_y, 3);
+        assert_eq!(refreshed.eval(&PrimeField::ZERO, &PrimeField::ZERO), secret);
+        assert!(delta_vm.is_zero_hole());
+        assert_eq!(delta_vm.dimensions(), vm.dimensions());
+
+        // The refreshed commitment is the sum of the old one and the delta.
+        let refreshed_vm = VerificationMatrix::from(&refreshed);
+        assert_eq!(&vm + &delta_vm, refreshed_vm);
+
+        // Shares change.
+        let x = PrimeField::from_u64(1);
+        let y = PrimeField::from_u64(2);
+        assert_ne!(refreshed.eval(&x, &y), bp.eval(&x, &y));
+        assert!(refreshed_vm.verify(&x, &y, &refreshed.eval(&x, &y)));
+        assert!(!refreshed_vm.verify(&x, &y, &bp.eval(&x, &y)));
+
+        // Refreshing again yields a different polynomial.
+        let (again, _) = refresh::<Group>(&bp, &mut rng);
+        assert!(again != refreshed);
+    }
+}
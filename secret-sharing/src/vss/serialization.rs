@@ -0,0 +1,199 @@
+//! Serde support for verification matrices and row proofs.
+//!
+//! Verification matrices and row proofs are serialized using their canonical byte
+//! representation, encoded as a byte string in binary formats and as
+//! a base64 string in human-readable formats.
+
+use std::fmt;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use group::{Group, GroupEncoding};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{RowProof, VerificationMatrix};
+
+impl<G> Serialize for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.to_bytes(), serializer)
+    }
+}
+
+impl<'de, G> Deserialize<'de> for VerificationMatrix<G>
+where
+    G: Group + GroupEncoding,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserialize_bytes(deserializer)?;
+        VerificationMatrix::try_from_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for RowProof {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RowProof {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserialize_bytes(deserializer)?;
+        RowProof::try_from_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes the given bytes as a byte string in binary formats
+/// and as a base64 string in human-readable formats.
+fn serialize_bytes<S: Serializer>(bytes: Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+    match serializer.is_human_readable() {
+        true => serializer.serialize_str(&STANDARD.encode(bytes)),
+        false => serializer.serialize_bytes(&bytes),
+    }
+}
+
+/// Deserializes bytes serialized by [`serialize_bytes`].
+fn deserialize_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    match deserializer.is_human_readable() {
+        true => {
+            let s = String::deserialize(deserializer)?;
+            STANDARD.decode(s).map_err(de::Error::custom)
+        }
+        false => deserializer.deserialize_byte_buf(BytesVisitor),
+    }
+}
+
+/// Visitor accepting byte strings as well as sequences of bytes.
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a byte string")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{poly, vss};
+
+    use super::RowProof;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    #[test]
+    fn test_cbor() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        // Serialized as a byte string.
+        let encoded = serde_cbor::to_vec(&vm).expect("serialization should succeed");
+        let bytes: serde_cbor::Value =
+            serde_cbor::from_slice(&encoded).expect("deserialization should succeed");
+        assert_eq!(bytes, serde_cbor::Value::Bytes(vm.to_bytes()));
+
+        let restored: VerificationMatrix =
+            serde_cbor::from_slice(&encoded).expect("deserialization should succeed");
+        assert_eq!(restored, vm);
+
+        // Wire size matches the byte representation (plus a 3-byte
+        // byte string header for lengths between 256 and 65535).
+        let size = VerificationMatrix::byte_size(3, 4);
+        assert_eq!(encoded.len(), 3 + size);
+
+        // Invalid matrix.
+        let mut bytes = vm.to_bytes();
+        bytes[2] = 0xFF;
+        let encoded = serde_cbor::to_vec(&serde_cbor::Value::Bytes(bytes)).unwrap();
+        let res: Result<VerificationMatrix, _> = serde_cbor::from_slice(&encoded);
+        assert!(res.is_err());
+
+        // Invalid length.
+        let bytes = vm.to_bytes();
+        let encoded = serde_cbor::to_vec(&serde_cbor::Value::Bytes(bytes[1..].to_vec())).unwrap();
+        let res: Result<VerificationMatrix, _> = serde_cbor::from_slice(&encoded);
+        assert!(res.is_err());
+
+        // Nested vectors of points are not accepted.
+        let encoded = serde_cbor::to_vec(&vec![vec![0u64; 4]; 3]).unwrap();
+        let res: Result<VerificationMatrix, _> = serde_cbor::from_slice(&encoded);
+        assert!(res.is_err());
+
+        // Garbage.
+        let res: Result<VerificationMatrix, _> = serde_cbor::from_slice(&[0xFF, 0x00, 0x01]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        // Serialized as a base64 string.
+        let encoded = serde_json::to_string(&vm).expect("serialization should succeed");
+        assert!(encoded.starts_with('"') && encoded.ends_with('"'));
+
+        let restored: VerificationMatrix =
+            serde_json::from_str(&encoded).expect("deserialization should succeed");
+        assert_eq!(restored, vm);
+
+        // Invalid encodings.
+        for encoded in ["\"AAE=\"", "\"not base64\"", "[0, 0]", "42"] {
+            let res: Result<VerificationMatrix, _> = serde_json::from_str(encoded);
+            assert!(res.is_err());
+        }
+    }
+
+    #[test]
+    fn test_row_proof() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let vm = VerificationMatrix::from(&BivariatePolynomial::random(15, 15, &mut rng));
+        let mc = vss::MatrixCommitment::new(&vm);
+        let proof = mc.prove(3).expect("row should exist");
+
+        let encoded = serde_cbor::to_vec(&proof).expect("serialization should succeed");
+        let restored: RowProof =
+            serde_cbor::from_slice(&encoded).expect("deserialization should succeed");
+        assert_eq!(restored, proof);
+        assert!(restored.verify(&mc.root()));
+
+        let encoded = serde_json::to_string(&proof).expect("serialization should succeed");
+        let restored: RowProof =
+            serde_json::from_str(&encoded).expect("deserialization should succeed");
+        assert_eq!(restored, proof);
+
+        // Truncated proof.
+        let bytes = proof.to_bytes();
+        let encoded = serde_cbor::to_vec(&serde_cbor::Value::Bytes(bytes[1..].to_vec())).unwrap();
+        let res: Result<RowProof, _> = serde_cbor::from_slice(&encoded);
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,202 @@
+//! Fixed-base scalar multiplication.
+
+use group::{ff::PrimeField, Group};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+use super::msm::{digit, to_le_bytes};
+
+/// The number of bits of a scalar processed per window.
+const WINDOW_SIZE: usize = 4;
+
+/// The number of precomputed multiples per window.
+const WINDOW_ENTRIES: usize = 1 << WINDOW_SIZE;
+
+/// Precomputed multiples of the generator for fast fixed-base scalar
+/// multiplication.
+///
+/// Scalars are split into windows of 4 bits, and the table holds
+/// the multiples `d * 2^{4k} * G` for every window `k` and digit `d`,
+/// so that a multiplication takes one addition per window and no doublings:
+/// ```text
+/// s * G = \sum_k s_k * 2^{4k} * G
+/// ```
+///
+/// Building the table costs about as much as a few scalar multiplications,
+/// so it pays off when many scalars are multiplied by the generator,
+/// e.g., when constructing verification matrices. The table can be shared
+/// between all of them.
+#[derive(Debug, Clone)]
+pub struct GeneratorTable<G: Group> {
+    /// The precomputed multiples, where `table[k * 16 + d]` represents
+    /// the element `d * 2^{4k} * G`.
+    table: Vec<G>,
+}
+
+impl<G> GeneratorTable<G>
+where
+    G: Group + ConditionallySelectable,
+{
+    /// Precomputes the multiples of the generator of the group.
+    pub fn new() -> Self {
+        Self::with_base(G::generator())
+    }
+
+    /// Precomputes the multiples of the given base, which is then used
+    /// in place of the generator of the group.
+    pub fn with_base(base: G) -> Self {
+        let windows = (G::Scalar::NUM_BITS as usize).div_ceil(WINDOW_SIZE);
+        let mut table = Vec::with_capacity(windows * WINDOW_ENTRIES);
+
+        let mut base = base; // 2^{4k} * G
+        for _ in 0..windows {
+            let mut entry = G::identity();
+            for _ in 0..WINDOW_ENTRIES {
+                table.push(entry);
+                entry += base;
+            }
+            base = entry; // 16 * 2^{4k} * G
+        }
+
+        Self { table }
+    }
+
+    /// Returns the generator multiplied by the given scalar.
+    ///
+    /// This method is constant time, as all multiples of a window are
+    /// scanned to select the one for the scalar's digit.
+    pub fn mul(&self, s: &G::Scalar) -> G {
+        let bytes = to_le_bytes(s);
+
+        let mut acc = G::identity();
+        for (k, entries) in self.table.chunks(WINDOW_ENTRIES).enumerate() {
+            let d = digit(bytes.as_ref(), k * WINDOW_SIZE, WINDOW_SIZE) as u8;
+
+            let mut entry = G::identity();
+            for (e, candidate) in entries.iter().enumerate() {
+                entry.conditional_assign(candidate, (e as u8).ct_eq(&d));
+            }
+
+            acc += entry;
+        }
+
+        acc
+    }
+}
+
+impl<G> Default for GeneratorTable<G>
+where
+    G: Group + ConditionallySelectable,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Multiplication of the generator by a scalar.
+///
+/// Every group falls back to the naive multiplication, while groups
+/// with a cached [`GeneratorTable`] specialize the implementation,
+/// so that generic code takes the fast path without additional bounds.
+pub(crate) trait GeneratorMul: Group {
+    /// Returns the generator multiplied by the given scalar.
+    fn mul_generator(s: &Self::Scalar) -> Self;
+}
+
+impl<G> GeneratorMul for G
+where
+    G: Group,
+{
+    default fn mul_generator(s: &G::Scalar) -> G {
+        G::generator() * s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use group::{ff::Field, Group as _, GroupEncoding};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::GeneratorMul;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type GeneratorTable = super::GeneratorTable<Group>;
+
+    #[test]
+    fn test_mul() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let table = GeneratorTable::new();
+
+        let mut scalars = vec![
+            PrimeField::ZERO,
+            PrimeField::ONE,
+            PrimeField::from_u64(15),
+            PrimeField::from_u64(16),
+            PrimeField::from_u64(0x0102_0304),
+            -PrimeField::ONE,
+        ];
+        scalars.extend((0..20).map(|_| PrimeField::random(&mut rng)));
+
+        for s in scalars {
+            assert_eq!(table.mul(&s), Group::generator() * s);
+        }
+    }
+
+    #[test]
+    fn test_mul_generator() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        // The cached table matches the naive path bit-for-bit.
+        for _ in 0..10 {
+            let s = PrimeField::random(&mut rng);
+            let expected = Group::generator() * s;
+            assert_eq!(Group::mul_generator(&s).to_bytes(), expected.to_bytes());
+        }
+    }
+
+    #[test]
+    fn test_with_base() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let base = Group::random(&mut rng);
+        let table = GeneratorTable::with_base(base);
+
+        for _ in 0..10 {
+            let s = PrimeField::random(&mut rng);
+            assert_eq!(table.mul(&s), base * s);
+        }
+    }
+
+    #[bench]
+    fn bench_mul(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let table = GeneratorTable::new();
+        let s = PrimeField::random(&mut rng);
+
+        b.iter(|| table.mul(&s));
+    }
+
+    #[bench]
+    fn bench_mul_generic(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let s = PrimeField::random(&mut rng);
+
+        b.iter(|| Group::generator() * s);
+    }
+
+    #[bench]
+    fn bench_mul_generator(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let s = PrimeField::random(&mut rng);
+
+        b.iter(|| Group::mul_generator(&s));
+    }
+
+    #[bench]
+    fn bench_new(b: &mut Bencher) {
+        b.iter(GeneratorTable::new);
+    }
+}
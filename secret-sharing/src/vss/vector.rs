@@ -1,7 +1,18 @@
-use group::Group;
-use subtle::Choice;
+use std::{
+    cmp::max,
+    ops::{Add, AddAssign},
+};
 
-use crate::poly::{powers, Polynomial};
+use group::{Group, GroupEncoding};
+use subtle::{Choice, ConditionallySelectable};
+
+use crate::poly::{ops::add_assign_slices, powers, Polynomial};
+
+use super::{Error, GeneratorMul, GeneratorTable, VerificationMatrix};
+
+/// The maximum number of elements of a verification vector that can be
+/// serialized, limited by the header which encodes the degree as one byte.
+const MAX_SERIALIZED_LEN: usize = u8::MAX as usize + 1;
 
 /// Verification vector for a univariate polynomial.
 ///
@@ -25,7 +36,7 @@ where
 {
     /// The verification vector elements, where `v[i]` represents the element
     /// `a_i * G`.
-    v: Vec<G>,
+    pub(crate) v: Vec<G>,
 }
 
 impl<G> VerificationVector<G>
@@ -53,7 +64,7 @@ where
         let mut verified = Choice::from(1);
 
         for (i, vi) in self.v.iter().enumerate() {
-            let diff = G::generator() * p.a[i] - vi;
+            let diff = G::mul_generator(&p.a[i]) - vi;
             verified &= diff.is_identity();
         }
 
@@ -63,7 +74,7 @@ where
     /// Verifies if the underlying univariate polynomial evaluates
     /// to the given value, i.e., if it holds `P(x) == v`.
     pub fn verify(&self, x: &G::Scalar, v: &G::Scalar) -> bool {
-        let mut diff = G::generator().neg() * v;
+        let mut diff = -G::mul_generator(v);
         let xpows = powers(x, self.v.len() - 1); // [x^i]
         for (i, xpow) in xpows.into_iter().enumerate() {
             diff += self.v[i] * xpow; // x^i * V_i = a_i x^i * G
@@ -73,19 +84,91 @@ where
     }
 }
 
+impl<G> VerificationVector<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Returns the byte representation of the verification vector.
+    ///
+    /// Layout: `[deg, elements...]`, where the degree is one less than
+    /// the number of elements, and elements are compressed.
+    ///
+    /// Returns an error if the vector is empty or has more than 256 elements.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        if self.v.is_empty() {
+            return Err(Error::EmptyMatrix);
+        }
+        if self.v.len() > MAX_SERIALIZED_LEN {
+            return Err(Error::DimensionsTooLarge);
+        }
+
+        let mut bytes = Vec::with_capacity(Self::byte_size(self.v.len()));
+        bytes.push((self.v.len() - 1) as u8);
+        for vi in &self.v {
+            bytes.extend_from_slice(vi.to_bytes().as_ref());
+        }
+
+        Ok(bytes)
+    }
+
+    /// Attempts to create a verification vector from its byte representation.
+    ///
+    /// This method is not constant time since the verification vector doesn't
+    /// contain sensitive information.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&deg, elements) = bytes.split_first()?;
+        let len = deg as usize + 1;
+
+        if bytes.len() != Self::byte_size(len) {
+            return None;
+        }
+
+        let element_size = VerificationMatrix::<G>::element_byte_size();
+        let mut v = Vec::with_capacity(len);
+
+        for chunk in elements.chunks(element_size) {
+            let mut repr: G::Repr = Default::default();
+            repr.as_mut().copy_from_slice(chunk);
+            v.push(Option::from(G::from_bytes(&repr))?);
+        }
+
+        Some(Self::new(v))
+    }
+
+    /// Returns the size of the byte representation of a verification vector
+    /// with the given number of elements.
+    pub fn byte_size(len: usize) -> usize {
+        1 + len * VerificationMatrix::<G>::element_byte_size()
+    }
+}
+
+impl<G> VerificationVector<G>
+where
+    G: Group + ConditionallySelectable,
+{
+    /// Constructs a new verification vector from the given univariate
+    /// polynomial, multiplying the generator using the given precomputed
+    /// table.
+    pub fn from_polynomial_with_table(
+        p: &Polynomial<G::Scalar>,
+        table: &GeneratorTable<G>,
+    ) -> Self {
+        Self::new(p.a.iter().map(|ai| table.mul(ai)).collect())
+    }
+}
+
 impl<G> From<&Polynomial<G::Scalar>> for VerificationVector<G>
 where
     G: Group,
 {
     /// Constructs a new verification vector from the given univariate
     /// polynomial.
+    ///
+    /// Supported suites multiply the generator using a cached
+    /// [`GeneratorTable`], other groups fall back to the naive
+    /// multiplication.
     fn from(p: &Polynomial<G::Scalar>) -> Self {
-        let mut v = Vec::with_capacity(p.size());
-        for ai in p.a.iter() {
-            v.push(G::generator() * ai)
-        }
-
-        Self::new(v)
+        Self::new(p.a.iter().map(G::mul_generator).collect())
     }
 }
 
@@ -100,9 +183,94 @@ where
     }
 }
 
+impl<G> Add for VerificationVector<G>
+where
+    G: Group,
+{
+    type Output = VerificationVector<G>;
+
+    #[inline]
+    fn add(self, rhs: Self) -> VerificationVector<G> {
+        &self + &rhs
+    }
+}
+
+impl<G> Add<&VerificationVector<G>> for VerificationVector<G>
+where
+    G: Group,
+{
+    type Output = VerificationVector<G>;
+
+    #[inline]
+    fn add(self, rhs: &VerificationVector<G>) -> VerificationVector<G> {
+        &self + rhs
+    }
+}
+
+impl<G> Add<VerificationVector<G>> for &VerificationVector<G>
+where
+    G: Group,
+{
+    type Output = VerificationVector<G>;
+
+    #[inline]
+    fn add(self, rhs: VerificationVector<G>) -> VerificationVector<G> {
+        self + &rhs
+    }
+}
+
+impl<G> Add for &VerificationVector<G>
+where
+    G: Group,
+{
+    type Output = VerificationVector<G>;
+
+    /// Adds the verification vectors, padding the shorter one with identity
+    /// elements, so that the result is the verification vector of the sum
+    /// of the univariate polynomials, regardless of their degrees.
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut v = self.v.clone();
+        v.resize(max(self.v.len(), rhs.v.len()), G::identity());
+        add_assign_slices(&mut v[..rhs.v.len()], &rhs.v);
+
+        VerificationVector::new(v)
+    }
+}
+
+impl<G> AddAssign for VerificationVector<G>
+where
+    G: Group,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: VerificationVector<G>) {
+        *self += &rhs
+    }
+}
+
+impl<G> AddAssign<&VerificationVector<G>> for VerificationVector<G>
+where
+    G: Group,
+{
+    /// Adds the verification vector in place, padding the shorter one
+    /// with identity elements, as [`Add`] does.
+    fn add_assign(&mut self, rhs: &VerificationVector<G>) {
+        if self.v.len() < rhs.v.len() {
+            self.v.resize(rhs.v.len(), G::identity());
+        }
+
+        add_assign_slices(&mut self.v[..rhs.v.len()], &rhs.v);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{poly::Polynomial, vss::VerificationVector};
+    use group::GroupEncoding;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        poly::Polynomial,
+        vss::{Error, GeneratorTable, VerificationVector},
+    };
 
     fn scalar(value: i64) -> p384::Scalar {
         scalars(&vec![value])[0]
@@ -125,6 +293,26 @@ mod tests {
         assert_eq!(vv.v.len(), 3);
     }
 
+    #[test]
+    fn test_from_polynomial_with_table() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let table = GeneratorTable::new();
+
+        // Small and large polynomials.
+        for deg in [2, 20] {
+            let p = Polynomial::<p384::Scalar>::random(deg, &mut rng);
+            let vv: VerificationVector<p384::ProjectivePoint> = VerificationVector::from(&p);
+            let restored = VerificationVector::from_polynomial_with_table(&p, &table);
+            assert_eq!(restored, vv);
+
+            // The default and the shared table match the naive path bit-for-bit.
+            for (vi, ai) in vv.v.iter().zip(p.a.iter()) {
+                let expected = p384::ProjectivePoint::GENERATOR * ai;
+                assert_eq!(vi.to_bytes(), expected.to_bytes());
+            }
+        }
+    }
+
     #[test]
     fn test_is_from() {
         let p = Polynomial::<p384::Scalar>::with_coefficients(scalars(&vec![1, 2, 3]));
@@ -145,4 +333,76 @@ mod tests {
         assert!(vv.verify(&x2, &s));
         assert!(!vv.verify(&x3, &s));
     }
+
+    #[test]
+    fn test_add() {
+        let p = Polynomial::<p384::Scalar>::with_coefficients(scalars(&[1, 2, 3]));
+        let q = Polynomial::<p384::Scalar>::with_coefficients(scalars(&[4, -5]));
+        let r = Polynomial::<p384::Scalar>::with_coefficients(scalars(&[5, -3, 3]));
+        let vp: VerificationVector<p384::ProjectivePoint> = VerificationVector::from(&p);
+        let vq: VerificationVector<p384::ProjectivePoint> = VerificationVector::from(&q);
+        let vr: VerificationVector<p384::ProjectivePoint> = VerificationVector::from(&r);
+
+        assert_eq!(&vp + &vq, vr);
+        assert_eq!(&vq + &vp, vr);
+        assert_eq!(vp.clone() + &vq, vr);
+        assert_eq!(&vp + vq.clone(), vr);
+        assert_eq!(vp.clone() + vq.clone(), vr);
+
+        let mut sum = vp.clone();
+        sum += &vq;
+        assert_eq!(sum, vr);
+
+        let mut sum = vq.clone();
+        sum += vp.clone();
+        assert_eq!(sum, vr);
+        assert!(sum.is_from(&r));
+
+        let x = scalar(7);
+        assert!(sum.verify(&x, &(p.eval(&x) + q.eval(&x))));
+    }
+
+    #[test]
+    fn test_serialization() {
+        type VerificationVector = super::VerificationVector<p384::ProjectivePoint>;
+
+        let p = Polynomial::<p384::Scalar>::with_coefficients(scalars(&[1, 2, -3]));
+        let vv = VerificationVector::from(&p);
+
+        let bytes = vv.to_bytes().expect("encoding should succeed");
+        assert_eq!(bytes.len(), VerificationVector::byte_size(3));
+        assert_eq!(bytes.len(), 1 + 3 * 49);
+        assert_eq!(bytes[0], 2);
+
+        let restored = VerificationVector::from_bytes(&bytes).expect("decoding should succeed");
+        assert_eq!(restored, vv);
+        assert!(restored.is_from(&p));
+
+        // Invalid length.
+        assert!(VerificationVector::from_bytes(&[]).is_none());
+        assert!(VerificationVector::from_bytes(&[0]).is_none());
+        assert!(VerificationVector::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+        let mut longer = bytes.clone();
+        longer[0] = 3;
+        assert!(VerificationVector::from_bytes(&longer).is_none());
+
+        // Invalid element.
+        let mut invalid = bytes.clone();
+        invalid[1 + 49] = 0xFF;
+        assert!(VerificationVector::from_bytes(&invalid).is_none());
+
+        // Maximum number of elements.
+        let p = Polynomial::<p384::Scalar>::with_coefficients(vec![scalar(1); 256]);
+        let vv = VerificationVector::from(&p);
+        let bytes = vv.to_bytes().expect("encoding should succeed");
+        assert_eq!(bytes[0], 255);
+        assert_eq!(VerificationVector::from_bytes(&bytes), Some(vv));
+
+        // Too many or no elements.
+        let p = Polynomial::<p384::Scalar>::with_coefficients(vec![scalar(1); 257]);
+        let vv = VerificationVector::from(&p);
+        assert!(matches!(vv.to_bytes(), Err(Error::DimensionsTooLarge)));
+        let vv = VerificationVector::new(vec![]);
+        assert!(matches!(vv.to_bytes(), Err(Error::EmptyMatrix)));
+    }
 }
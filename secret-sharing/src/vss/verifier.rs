@@ -0,0 +1,80 @@
+use group::Group;
+
+use super::VerificationVector;
+
+/// Verifier for repeated checks at a fixed `x` value.
+///
+/// The verifier folds the rows of the verification matrix for the given
+/// `x` value once:
+/// ```text
+///     W_j = \sum_{i=0}^{deg_x} x^i * M_{i,j}
+/// ```
+/// so that each subsequent check only needs `deg_y + 1` scalar
+/// multiplications instead of `(deg_x + 1) * (deg_y + 1)`.
+#[derive(Debug, Clone)]
+pub struct VerificationMatrixVerifier<G: Group> {
+    /// The fixed `x` value.
+    x: G::Scalar,
+    /// The verification vector for the univariate polynomial `B(x,y)`
+    /// in the indeterminate `y`.
+    vv: VerificationVector<G>,
+}
+
+impl<G> VerificationMatrixVerifier<G>
+where
+    G: Group,
+{
+    /// Creates a new verifier for the given `x` value and the verification
+    /// vector of the polynomial `B(x,y)`.
+    pub(crate) fn new(x: G::Scalar, vv: VerificationVector<G>) -> Self {
+        Self { x, vv }
+    }
+
+    /// Returns the fixed `x` value.
+    pub fn x(&self) -> &G::Scalar {
+        &self.x
+    }
+
+    /// Verifies whether the underlying bivariate polynomial evaluates
+    /// to the given value, i.e., if it holds `B(x,y) == v`.
+    pub fn verify_at_y(&self, y: &G::Scalar, v: &G::Scalar) -> bool {
+        self.vv.verify(y, v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{poly, vss};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+
+    #[test]
+    fn test_verify_at_y() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        for _ in 0..5 {
+            let x = PrimeField::random(&mut rng);
+            let verifier = vm.prepare_x(&x);
+            assert_eq!(verifier.x(), &x);
+
+            for _ in 0..5 {
+                let y = PrimeField::random(&mut rng);
+                let v = bp.eval(&x, &y);
+                assert_eq!(verifier.verify_at_y(&y, &v), vm.verify(&x, &y, &v));
+                assert!(verifier.verify_at_y(&y, &v));
+
+                let v = v + PrimeField::ONE;
+                assert_eq!(verifier.verify_at_y(&y, &v), vm.verify(&x, &y, &v));
+                assert!(!verifier.verify_at_y(&y, &v));
+            }
+        }
+    }
+}
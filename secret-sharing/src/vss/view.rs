@@ -0,0 +1,267 @@
+use std::marker::PhantomData;
+
+use group::{Group, GroupEncoding};
+
+use crate::poly::powers;
+
+use super::{msm::msm, DecodeError, GeneratorMul, PointEncoding, VerificationMatrix};
+
+/// Read-only view of a verification matrix in its byte representation.
+///
+/// Only the header and the total length are validated when the view
+/// is created, while elements are decoded lazily when accessed. This saves
+/// decompressing all `rows * cols` elements when only a few of them,
+/// or a single evaluation, need to be checked.
+///
+/// Since elements are decoded on every access, a fully decoded
+/// [`VerificationMatrix`] should be preferred when the matrix is used
+/// repeatedly.
+#[derive(Debug, Clone, Copy)]
+pub struct VerificationMatrixView<'a, G: Group> {
+    /// The number of rows in the verification matrix.
+    rows: usize,
+    /// The number of columns in the verification matrix.
+    cols: usize,
+    /// The encoded elements in row-major order, without the header.
+    elements: &'a [u8],
+    _group: PhantomData<G>,
+}
+
+impl<'a, G> VerificationMatrixView<'a, G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Attempts to create a view of a verification matrix from its byte
+    /// representation, as produced by [`VerificationMatrix::to_bytes`].
+    ///
+    /// Both the legacy and the extended format are accepted. Elements are
+    /// not decoded, so an invalid element is only reported when accessed.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        let (format, rows, cols) =
+            VerificationMatrix::<G>::decode_header(bytes, PointEncoding::Compressed)?;
+        let expected_len = VerificationMatrix::<G>::checked_byte_size_versioned(format, rows, cols)
+            .ok_or(DecodeError::SizeOverflow)?;
+
+        if bytes.len() != expected_len {
+            return Err(DecodeError::LengthMismatch {
+                expected: expected_len,
+                got: bytes.len(),
+            });
+        }
+
+        Ok(Self {
+            rows,
+            cols,
+            elements: &bytes[format.header_size()..],
+            _group: PhantomData,
+        })
+    }
+
+    /// Returns the dimensions (number of rows and columns) of the verification
+    /// matrix.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the degree of the bivariate polynomial in the `x` variable.
+    pub fn deg_x(&self) -> usize {
+        self.rows - 1
+    }
+
+    /// Returns the degree of the bivariate polynomial in the `y` variable.
+    pub fn deg_y(&self) -> usize {
+        self.cols - 1
+    }
+
+    /// Decodes and returns the element `M_{i,j}`, or `None` if the indices
+    /// are out of bounds.
+    ///
+    /// Returns an error if the element is invalid.
+    pub fn element(&self, i: usize, j: usize) -> Result<Option<G>, DecodeError> {
+        if i >= self.rows || j >= self.cols {
+            return Ok(None);
+        }
+
+        self.decode_element(i, j).map(Some)
+    }
+
+    /// Decodes the elements of the `i`-th row into the given vector,
+    /// replacing its contents.
+    fn decode_row(&self, i: usize, row: &mut Vec<G>) -> Result<(), DecodeError> {
+        row.clear();
+        for j in 0..self.cols {
+            row.push(self.decode_element(i, j)?);
+        }
+
+        Ok(())
+    }
+
+    /// Decodes the element `M_{i,j}`, which must be within bounds.
+    fn decode_element(&self, i: usize, j: usize) -> Result<G, DecodeError> {
+        let size = VerificationMatrix::<G>::element_byte_size();
+        let start = (i * self.cols + j) * size;
+
+        let mut repr: G::Repr = Default::default();
+        repr.as_mut()
+            .copy_from_slice(&self.elements[start..start + size]);
+
+        Option::from(G::from_bytes(&repr)).ok_or(DecodeError::InvalidElement { row: i, col: j })
+    }
+
+    /// Verifies whether the underlying bivariate polynomial evaluates
+    /// to the given value, i.e., if it holds `B(x,y) == v`.
+    ///
+    /// Elements are decoded one row at a time and folded into the sum
+    /// as they are decoded, so the whole matrix is never held in memory.
+    /// Returns an error if an element is invalid.
+    ///
+    /// The value is handled in constant time. The point `(x,y)` is assumed
+    /// to be public, as the multi-scalar multiplication over the powers
+    /// of its coordinates is not constant time.
+    pub fn verify(&self, x: &G::Scalar, y: &G::Scalar, v: &G::Scalar) -> Result<bool, DecodeError> {
+        let xpows = powers(x, self.deg_x()); // [x^i]
+        let ypows = powers(y, self.deg_y()); // [y^j]
+
+        let mut row = Vec::with_capacity(self.cols);
+        let mut scalars = Vec::with_capacity(self.cols);
+        let mut sum = G::identity();
+        for (i, xpow) in xpows.iter().enumerate() {
+            self.decode_row(i, &mut row)?;
+            scalars.clear();
+            scalars.extend(ypows.iter().map(|ypow| *xpow * ypow)); // x^i * y^j
+            sum += msm(&scalars, &row); // \sum_j x^i * y^j * M_{i,j}
+        }
+
+        // The value may be secret, so keep it out of the multi-scalar
+        // multiplication, which is not constant time.
+        sum -= G::mul_generator(v);
+
+        Ok(sum.is_identity().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        poly,
+        vss::{self, DecodeError},
+    };
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type BivariatePolynomial = poly::BivariatePolynomial<PrimeField>;
+    type VerificationMatrix = vss::VerificationMatrix<Group>;
+    type VerificationMatrixView<'a> = vss::VerificationMatrixView<'a, Group>;
+
+    #[test]
+    fn test_new() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let vm = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        let bytes = vm.to_bytes();
+
+        let view = VerificationMatrixView::new(&bytes).expect("view should be created");
+        assert_eq!(view.dimensions(), (3, 4));
+        assert_eq!(view.deg_x(), 2);
+        assert_eq!(view.deg_y(), 3);
+
+        let res = VerificationMatrixView::new(&bytes[..1]);
+        assert_eq!(res.unwrap_err(), DecodeError::TooShort);
+
+        let res = VerificationMatrixView::new(&bytes[..bytes.len() - 1]);
+        assert_eq!(
+            res.unwrap_err(),
+            DecodeError::LengthMismatch {
+                expected: bytes.len(),
+                got: bytes.len() - 1,
+            }
+        );
+
+        // Extended format.
+        let b = vec![vec![PrimeField::ONE; 301]];
+        let vm = VerificationMatrix::from(&BivariatePolynomial::with_coefficients(b));
+        let bytes = vm.to_bytes();
+        let view = VerificationMatrixView::new(&bytes).expect("view should be created");
+        assert_eq!(view.dimensions(), (1, 301));
+        assert_eq!(view.element(0, 300), Ok(vm.element(0, 300).copied()));
+    }
+
+    #[test]
+    fn test_element() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let vm = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        let bytes = vm.to_bytes();
+        let view = VerificationMatrixView::new(&bytes).expect("view should be created");
+
+        for (i, j, mij) in vm.iter() {
+            assert_eq!(view.element(i, j), Ok(Some(*mij)));
+        }
+        assert_eq!(view.element(3, 0), Ok(None));
+        assert_eq!(view.element(0, 4), Ok(None));
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let bytes = vm.to_bytes();
+        let view = VerificationMatrixView::new(&bytes).expect("view should be created");
+
+        for _ in 0..5 {
+            let x = PrimeField::random(&mut rng);
+            let y = PrimeField::random(&mut rng);
+            let v = bp.eval(&x, &y);
+
+            assert_eq!(view.verify(&x, &y, &v), Ok(true));
+            assert_eq!(view.verify(&x, &y, &(v + PrimeField::ONE)), Ok(false));
+            assert_eq!(view.verify(&x, &y, &v), Ok(vm.verify(&x, &y, &v)));
+        }
+    }
+
+    #[test]
+    fn test_corrupted() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let size = VerificationMatrix::element_byte_size();
+
+        let mut bytes = vm.to_bytes();
+        bytes[2 + (4 + 2) * size] = 0xFF; // m_{1,2}
+
+        // The header is valid, so the corruption goes unnoticed until
+        // the element is touched.
+        let view = VerificationMatrixView::new(&bytes).expect("view should be created");
+        assert_eq!(view.element(1, 1), Ok(vm.element(1, 1).copied()));
+        assert_eq!(view.element(2, 3), Ok(vm.element(2, 3).copied()));
+
+        let err = DecodeError::InvalidElement { row: 1, col: 2 };
+        assert_eq!(view.element(1, 2), Err(err));
+
+        let x = PrimeField::random(&mut rng);
+        let y = PrimeField::random(&mut rng);
+        let v = bp.eval(&x, &y);
+        assert_eq!(view.verify(&x, &y, &v), Err(err));
+    }
+
+    #[bench]
+    fn bench_verify_30_60(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(30, 60, &mut rng);
+        let bytes = VerificationMatrix::from(&bp).to_bytes();
+        let x = PrimeField::random(&mut rng);
+        let y = PrimeField::random(&mut rng);
+        let v = bp.eval(&x, &y);
+
+        b.iter(|| {
+            let view = VerificationMatrixView::new(&bytes).expect("view should be created");
+            view.verify(&x, &y, &v)
+        });
+    }
+}
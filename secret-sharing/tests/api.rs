@@ -0,0 +1,48 @@
+//! Tests that the supported public API suffices for a full dealing.
+//!
+//! Nothing outside of the `api` module may be imported here.
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use secret_sharing::api::{
+    p384, Dealer, HandoffKind, KeyRecoverer, Player, Suite, VerifiableSecretShare,
+};
+
+type Group = <p384::Sha3_384 as Suite>::Group;
+type PrimeField = <p384::Sha3_384 as Suite>::PrimeField;
+
+#[test]
+fn test_deal_verify_recover() {
+    let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+    let threshold = 2;
+    let kind = HandoffKind::DealingPhase;
+
+    // Deal.
+    let dealer = Dealer::<Group>::new(threshold, &mut rng).expect("dealing should succeed");
+    let player = Player::new(threshold, kind);
+    let xs: Vec<_> = (1..=player.min_shares() as u64)
+        .map(PrimeField::from_u64)
+        .collect();
+    let shares = dealer.make_shares(xs.clone(), kind);
+
+    // Shareholders receive the verification matrix in its byte representation.
+    let bytes = dealer.verification_matrix().to_bytes();
+    let vm = Dealer::<Group>::decode_verification_matrix(threshold, &bytes)
+        .expect("decoding should succeed");
+    assert_eq!(&vm, dealer.verification_matrix());
+
+    // Verify.
+    for share in shares {
+        let share = VerifiableSecretShare::new(share, vm.clone());
+        share
+            .verify(threshold, false, true)
+            .expect("share should be valid");
+    }
+
+    // Recover.
+    let shares = dealer.make_shares(xs, kind);
+    let secret = player
+        .recover_secret(&shares)
+        .expect("recovery should succeed");
+    assert!(vm.secret_commitment().verify_recovered(&secret));
+}